@@ -9,14 +9,25 @@
 
 use core::fmt;
 use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut, Range};
+use core::slice::ChunksExactMut;
 
 use crate::initializer::BufferInitializer;
-use crate::traits::{Initialize, TrustedDeref};
-use crate::wrappers::AsUninit;
+use crate::traits::{GrowableBacking, Initialize, TrustedDeref};
+use crate::wrappers::{AsUninit, AssertInit};
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+/// A buffer that tracks how much of its inner container has been filled, and initialized.
+///
+/// Note that the buffer never drops the items it has initialized, since the inner container only
+/// stores them as `MaybeUninit`. For item types with destructors, the items will thus be leaked,
+/// unless ownership is reclaimed, e.g. using [`into_filled_vec`](Buffer::into_filled_vec).
 pub struct Buffer<T> {
     pub(crate) initializer: BufferInitializer<T>,
     pub(crate) items_filled: usize,
+    pub(crate) items_consumed: usize,
 }
 
 /// A reference to a [`Buffer`], which is meant be a subset of the functionality offered by the
@@ -24,6 +35,7 @@ pub struct Buffer<T> {
 ///
 /// For example, it neither allows reading from the unfilled region, nor swapping out the buffer
 /// pointed to, with anything else.
+#[must_use]
 pub struct BufferRef<'buffer, T> {
     // NOTE: The reference here is private, and never accessed using the API, _since we don't want
     // an API user to be able to replace a `&mut Buffer` with a completely different one_.
@@ -50,6 +62,7 @@ impl<T> Buffer<T> {
         Self {
             initializer,
             items_filled: 0,
+            items_consumed: 0,
         }
     }
     /// Create a new buffer, defaulting to not being initialized, nor filled. Prefer
@@ -64,6 +77,7 @@ impl<T> Buffer<T> {
         let Self {
             initializer,
             items_filled,
+            ..
         } = self;
 
         (initializer, items_filled)
@@ -83,6 +97,33 @@ impl<T> Buffer<T> {
         self.into_initializer().into_inner()
     }
 
+    /// Transform the inner container into a different type, e.g. a vector into a boxed slice,
+    /// keeping the filled, initialized and consumed cursors as they are.
+    ///
+    /// # Safety
+    ///
+    /// Since the cursors are kept, `f` must return a container with exactly the same length and
+    /// the same contents as the one it was given, as far as [`Initialize`] is concerned.
+    #[inline]
+    pub unsafe fn map_backing<U, F>(self, f: F) -> Buffer<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        let Self {
+            initializer,
+            items_filled,
+            items_consumed,
+        } = self;
+
+        Buffer {
+            initializer: BufferInitializer {
+                inner: f(initializer.inner),
+                items_initialized: initializer.items_initialized,
+            },
+            items_filled,
+            items_consumed,
+        }
+    }
     /// Get the number of items that are currently filled, within the buffer. Note that this is
     /// different from the number of initialized items; use [`items_initialized`] for that.
     ///
@@ -91,6 +132,13 @@ impl<T> Buffer<T> {
     pub const fn items_filled(&self) -> usize {
         self.items_filled
     }
+    /// Get the number of filled items that have been consumed, using [`consume`].
+    ///
+    /// [`consume`]: #method.consume
+    #[inline]
+    pub const fn consumed(&self) -> usize {
+        self.items_consumed
+    }
 
     #[inline]
     pub fn by_ref(&mut self) -> BufferRef<'_, T> {
@@ -129,6 +177,54 @@ where
         self.initializer.debug_assert_validity();
         debug_assert!(self.items_filled() <= self.capacity());
         debug_assert!(self.items_filled() <= self.initializer.items_initialized());
+        debug_assert!(self.items_consumed <= self.items_filled());
+    }
+    #[cfg(feature = "strict")]
+    pub(crate) fn assert_validity(&self) {
+        let items_initialized = self.initializer.items_initialized();
+
+        assert!(
+            items_initialized <= self.capacity(),
+            "buffer has {} items initialized, but a capacity of only {}",
+            items_initialized,
+            self.capacity()
+        );
+        assert!(
+            self.items_filled <= items_initialized,
+            "buffer has {} items filled, but only {} items initialized",
+            self.items_filled,
+            items_initialized
+        );
+        assert!(
+            self.items_consumed <= self.items_filled,
+            "buffer has {} items consumed, but only {} items filled",
+            self.items_consumed,
+            self.items_filled
+        );
+    }
+    /// Get a guard that dereferences to the buffer, and that checks its invariants when dropped.
+    /// This is a debugging aid for code using the unsafe APIs, catching invariant violations at
+    /// the end of a scope, rather than at the next method call.
+    ///
+    /// The checks are only done in debug builds, unless the `strict` feature is enabled.
+    #[inline]
+    pub fn guarded(&mut self) -> BufferGuard<'_, T> {
+        BufferGuard { inner: self }
+    }
+    /// Get a cursor to the unfilled part of the buffer, which records how many items have been
+    /// written through it and advances the buffer by that count when dropped, and which forgets
+    /// the initialized-but-unfilled part once it is mutably dereferenced.
+    ///
+    /// Since the cursor allows arbitrary values, including uninitialized ones, to be written to the
+    /// unfilled part, the initialized-but-unfilled part cannot be trusted after that. Until then it
+    /// is kept, so that creating a cursor alone does not lose it, and items of it can be recorded
+    /// without being written.
+    #[inline]
+    pub fn fill_cursor(&mut self) -> FillCursor<'_, T> {
+        FillCursor {
+            inner: self,
+            recorded: 0,
+        }
     }
     /// Get the number of items that may be filled before the buffer is full.
     #[inline]
@@ -146,6 +242,53 @@ where
     pub fn is_empty(&self) -> bool {
         self.items_filled() == 0
     }
+    /// Turn a completely filled buffer into its initialized backing, e.g. to share it read-only
+    /// once it has been filled, like `BytesMut::freeze` in the `bytes` crate. If the buffer is not
+    /// yet full, it is returned back as is.
+    #[inline]
+    pub fn try_freeze(self) -> Result<AssertInit<T>, Self> {
+        if self.is_full() {
+            // SAFETY: Every item is filled, and thus also initialized.
+            Ok(unsafe { self.into_initializer().assume_init() })
+        } else {
+            Err(self)
+        }
+    }
+    /// Turn a completely filled buffer into its initialized backing. See
+    /// [`try_freeze`](Self::try_freeze).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the buffer is not full.
+    #[inline]
+    pub fn freeze(self) -> AssertInit<T> {
+        match self.try_freeze() {
+            Ok(init) => init,
+            Err(buffer) => panic!(
+                "cannot freeze a buffer with only {} out of {} items filled",
+                buffer.items_filled(),
+                buffer.capacity()
+            ),
+        }
+    }
+    /// Get the index of the first uninitialized item, or `None` if the buffer is fully
+    /// initialized.
+    #[inline]
+    pub fn first_uninit_offset(&self) -> Option<usize> {
+        let items_initialized = self.initializer.items_initialized();
+        Some(items_initialized).filter(|&offset| offset < self.capacity())
+    }
+    /// Get the index of the first unfilled item, or `None` if the buffer is full.
+    #[inline]
+    pub fn first_unfilled_offset(&self) -> Option<usize> {
+        Some(self.items_filled).filter(|&offset| offset < self.capacity())
+    }
+    /// Get the number of items that are initialized but not yet filled, and which can hence be
+    /// reused without being initialized again.
+    #[inline]
+    pub fn reusable_init_len(&self) -> usize {
+        self.initializer.items_initialized() - self.items_filled
+    }
     /// Retrieve a shared slice to the filled part of the buffer.
     #[inline]
     pub fn filled_part(&self) -> &[T::Item] {
@@ -158,6 +301,23 @@ where
             core::slice::from_raw_parts(ptr as *const T::Item, len)
         }
     }
+    /// Iterate over the filled parts of this buffer and then `other`, skipping them if empty, e.g.
+    /// to write both using vectored I/O without concatenating them first.
+    #[inline]
+    pub fn chain<'a, U>(&'a self, other: &'a Buffer<U>) -> impl Iterator<Item = &'a [T::Item]> + 'a
+    where
+        U: Initialize<Item = T::Item>,
+    {
+        core::iter::once(self.filled_part())
+            .chain(core::iter::once(other.filled_part()))
+            .filter(|filled_part| !filled_part.is_empty())
+    }
+    /// Iterate over the filled part of the buffer. The unfilled part is never iterated over, even
+    /// if it has been initialized.
+    #[inline]
+    pub fn iter_filled(&self) -> core::slice::Iter<'_, T::Item> {
+        self.filled_part().iter()
+    }
     /// Retrieve a mutable slice to the filled part of the buffer.
     #[inline]
     pub fn filled_part_mut(&mut self) -> &mut [T::Item] {
@@ -172,6 +332,104 @@ where
             core::slice::from_raw_parts_mut(ptr as *mut T::Item, len)
         }
     }
+    /// Fold over the filled items, e.g. to compute a sum or a checksum. Only the filled part is
+    /// visited, regardless of how much else is initialized.
+    #[inline]
+    pub fn fold_filled<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T::Item) -> B,
+    {
+        self.filled_part().iter().fold(init, f)
+    }
+    /// Map every filled item through `f`, collecting the results into a newly allocated vector,
+    /// e.g. to turn received bytes into a vector of some other type.
+    #[cfg(feature = "alloc")]
+    pub fn map_filled_into_vec<U, F>(&self, f: F) -> Vec<U>
+    where
+        F: FnMut(&T::Item) -> U,
+    {
+        self.filled_part().iter().map(f).collect()
+    }
+    /// Map every filled item through `f` into a new heap-allocated buffer, which will be fully
+    /// filled with the mapped items. This always allocates, even if the item type stays the same.
+    #[cfg(feature = "alloc")]
+    pub fn map_into<U, F>(&self, mut f: F) -> Buffer<Vec<MaybeUninit<U>>>
+    where
+        F: FnMut(T::Item) -> U,
+        T::Item: Copy,
+    {
+        let vec = self
+            .filled_part()
+            .iter()
+            .map(|&item| MaybeUninit::new(f(item)))
+            .collect::<Vec<_>>();
+        let len = vec.len();
+
+        let mut initializer = BufferInitializer::uninit(vec);
+        // SAFETY: Every item of the vector was initialized with a mapped value.
+        unsafe {
+            initializer.advance_to_end();
+        }
+        let mut buffer = Buffer::from_initializer(initializer);
+        buffer.items_filled = len;
+        buffer
+    }
+    /// Retrieve the part of the filled part that has not yet been consumed, when using the buffer
+    /// as a read-ahead cache.
+    #[inline]
+    pub fn readable(&self) -> &[T::Item] {
+        &self.filled_part()[self.items_consumed..]
+    }
+    /// Mark `count` items of the [`readable`](Self::readable) part as consumed. Once everything
+    /// that was filled has been consumed, both the filledness cursor and the consumed cursor are
+    /// reset to zero, so that the buffer can be filled again from the start. The initialized part
+    /// is kept.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `count` is larger than the number of readable items.
+    #[inline]
+    pub fn consume(&mut self, count: usize) {
+        let readable = self.items_filled - self.items_consumed;
+        assert!(
+            count <= readable,
+            "cannot consume {} items when only {} items are readable",
+            count,
+            readable
+        );
+        self.items_consumed += count;
+
+        if self.items_consumed == self.items_filled {
+            self.items_consumed = 0;
+            self.items_filled = 0;
+        }
+    }
+    /// Iterate over mutable chunks of exactly `chunk_size` items of the filled part, e.g. for
+    /// in-place block processing. The items that do not fit in a whole chunk can be retrieved
+    /// using [`ChunksExactMut::into_remainder`](core::slice::ChunksExactMut::into_remainder).
+    ///
+    /// ```
+    /// # use uninit_tools::buffer::Buffer;
+    /// let mut storage = [0_u8; 40];
+    /// let mut buffer = Buffer::new(&mut storage[..]);
+    /// buffer.append(&[1; 36]);
+    ///
+    /// let mut blocks = buffer.filled_chunks_exact_mut(16);
+    /// for block in &mut blocks {
+    ///     block.iter_mut().for_each(|byte| *byte ^= 0xFF);
+    /// }
+    /// assert_eq!(blocks.into_remainder(), &[1; 4]);
+    ///
+    /// assert!(buffer.filled_part()[..32].iter().all(|&byte| byte == 0xFE));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `chunk_size` is zero.
+    #[inline]
+    pub fn filled_chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<'_, T::Item> {
+        self.filled_part_mut().chunks_exact_mut(chunk_size)
+    }
     /// Get a shared slice to the unfilled part, which may be uninitialized.
     #[inline]
     pub fn unfilled_part(&self) -> &[MaybeUninit<T::Item>] {
@@ -236,6 +494,20 @@ where
         }
     }
 
+    /// Get the index ranges of the filled part, the unfilled but initialized part, and the
+    /// unfilled and uninitialized part, in that order. These are computed purely from the cursors,
+    /// and are thus consistent with [`all_parts`](Self::all_parts).
+    #[inline]
+    pub fn part_offsets(&self) -> (Range<usize>, Range<usize>, Range<usize>) {
+        let items_filled = self.items_filled;
+        let items_initialized = self.initializer.items_initialized();
+
+        (
+            0..items_filled,
+            items_filled..items_initialized,
+            items_initialized..self.capacity(),
+        )
+    }
     #[inline]
     pub fn all_parts_mut(&mut self) -> BufferPartsMut<'_, T::Item> {
         let (all_ptr, all_len) = unsafe {
@@ -333,6 +605,52 @@ where
 
         unfilled_init_part
     }
+    /// Reinterpret the filled part of the buffer as a slice of `U`, e.g. to read a binary record
+    /// without copying it. Since the filled part is always initialized, this is safe for any
+    /// [`AnyBitPattern`](bytemuck::AnyBitPattern) type.
+    ///
+    /// Returns `None` if the filled part is not properly aligned for `U`, or if its size is not a
+    /// multiple of the size of `U`.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn filled_as<U>(&self) -> Option<&[U]>
+    where
+        T::Item: bytemuck::NoUninit,
+        U: bytemuck::AnyBitPattern,
+    {
+        bytemuck::try_cast_slice(self.filled_part()).ok()
+    }
+    /// Reinterpret the filled part of the buffer as a mutable slice of `U`, allowing typed fields
+    /// to be edited in place. Since the filled part is always initialized, this is safe for any
+    /// [`Pod`](bytemuck::Pod) type.
+    ///
+    /// Returns `None` if the filled part is not properly aligned for `U`, or if its size is not a
+    /// multiple of the size of `U`.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn filled_as_mut<U>(&mut self) -> Option<&mut [U]>
+    where
+        T::Item: bytemuck::Pod,
+        U: bytemuck::Pod,
+    {
+        bytemuck::try_cast_slice_mut(self.filled_part_mut()).ok()
+    }
+    /// Write into the initialized-but-unfilled part of the buffer through `f`, and then advance
+    /// the filledness cursor by the number of items that `f` returns. This allows already
+    /// initialized items to be reused, without having to initialize them again.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the count returned by `f` is larger than the length of the slice that
+    /// was passed to it.
+    #[inline]
+    pub fn append_into_init_tail<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut [T::Item]) -> usize,
+    {
+        let count = f(self.unfilled_init_part_mut());
+        self.advance(count);
+    }
     #[inline]
     pub fn unfilled_uninit_part(&self) -> &[MaybeUninit<T::Item>] {
         self.initializer.uninit_part()
@@ -342,6 +660,18 @@ where
     pub fn unfilled_uninit_part_mut(&mut self) -> &mut [MaybeUninit<T::Item>] {
         self.initializer.uninit_part_mut()
     }
+    /// Get the uninitialized part of the buffer, named like `Vec::spare_capacity_mut`. This is
+    /// the same as [`unfilled_uninit_part_mut`](Self::unfilled_uninit_part_mut), and thus
+    /// excludes any items that are initialized but not filled.
+    ///
+    /// After writing to the start of it, the written items can be marked as initialized using
+    /// [`BufferInitializer::advance`] via [`initializer_mut`](Self::initializer_mut), or, if
+    /// nothing was initialized but not filled, as both initialized and filled using
+    /// [`assume_init`](Self::assume_init).
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T::Item>] {
+        self.unfilled_uninit_part_mut()
+    }
 
     #[inline]
     pub fn unfilled_parts(&mut self) -> (&[T::Item], &[MaybeUninit<T::Item>]) {
@@ -369,374 +699,4191 @@ where
     pub fn revert_to_start(&mut self) {
         self.by_ref().revert_to_start()
     }
+    /// Append `slice` to the buffer, marking it as both initialized and filled. Items that were
+    /// already initialized, e.g. from a previous use of the buffer, are overwritten directly, and
+    /// only the rest of `slice` extends the initialized part.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `slice` does not fit in the unfilled part.
     #[inline]
     pub fn append(&mut self, slice: &[T::Item])
     where
         T::Item: Copy,
     {
-        unsafe {
-            // TODO: If this would ever turn out to be worth it, this could be optimized as bounds
-            // checking gets redundant here.
-            let unfilled_part = self.unfilled_part_mut();
-            assert!(slice.len() <= unfilled_part.len());
-            unfilled_part[..slice.len()].copy_from_slice(crate::cast_init_to_uninit_slice(slice));
+        assert!(slice.len() <= self.remaining());
 
-            self.assume_init(slice.len())
-        }
-    }
-    #[inline]
-    pub fn advance(&mut self, count: usize) {
-        assert!(
-            self.initializer
-                .items_initialized()
-                .wrapping_sub(self.items_filled)
-                >= count,
-            "advancing filledness cursor beyond the initialized region ({} + {} = {} filled > {} init)",
-            self.items_filled,
-            count,
-            self.items_filled + count,
-            self.initializer.items_initialized,
-        );
-        self.items_filled = self.items_filled.wrapping_add(count);
-    }
-    #[inline]
-    pub fn advance_to_init_part(&mut self) {
-        self.items_filled = self.initializer.items_initialized;
+        let (unfilled_init_part, unfilled_uninit_part) = self.unfilled_parts_mut();
+        let init_count = core::cmp::min(slice.len(), unfilled_init_part.len());
+        let (init_src, uninit_src) = slice.split_at(init_count);
+
+        unfilled_init_part[..init_count].copy_from_slice(init_src);
+        unfilled_uninit_part[..uninit_src.len()]
+            .copy_from_slice(crate::cast_init_to_uninit_slice(uninit_src));
+
+        unsafe { self.assume_init(slice.len()) }
     }
-    // TODO: Method for increasing the items filled, but not the items initialized?
-    /// Increment the counter that marks the progress of filling, as well as the initialization
-    /// progress, `count` items.
+    /// Append possibly-uninitialized items to the buffer, marking them as both initialized and
+    /// filled. This is useful when forwarding between buffers, where the source is only known to
+    /// be initialized through other means.
     ///
     /// # Safety
     ///
-    /// This does not initialize nor fill anything, and it is hence up to the user to ensure that
-    /// no uninitialized items are marked initialized.
-    pub unsafe fn assume_init(&mut self, count: usize) {
-        self.items_filled += count;
-        self.initializer.items_initialized =
-            core::cmp::max(self.items_filled, self.initializer.items_initialized);
-
-        self.debug_assert_validity();
-    }
-    /// Mark the buffer as fully filled and initialized, without actually filling the buffer.
+    /// While copying the items themselves never reads them as initialized, the caller must ensure
+    /// that all of `slice` is initialized, since the items are subsequently exposed as part of the
+    /// filled part.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// For this to be safe, the caller must ensure that every single item in the slice that the
-    /// buffer wraps, is initialized.
+    /// This will panic if `slice` does not fit in the unfilled part.
     #[inline]
-    pub unsafe fn assume_init_all(&mut self) {
-        self.items_filled = self.capacity();
-        self.initializer.items_initialized = self.capacity();
+    pub unsafe fn append_maybe_uninit(&mut self, slice: &[MaybeUninit<T::Item>])
+    where
+        T::Item: Copy,
+    {
+        let unfilled_part = self.unfilled_part_mut();
+        assert!(slice.len() <= unfilled_part.len());
+        unfilled_part[..slice.len()].copy_from_slice(slice);
+
+        self.assume_init(slice.len())
     }
-    #[inline]
-    pub fn fill_by_repeating(&mut self, item: T::Item)
+    /// Copy the filled part of `src` into this buffer, starting at `dst_offset`. The copied items
+    /// are marked as initialized, and if the copy is contiguous with (or overlaps) the filled part,
+    /// they are also marked as filled. Copies that leave a gap after the filled part will only
+    /// extend the initialized part, so that the gap can be filled by subsequent copies, e.g. when
+    /// reassembling a message from fragments received out of order.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the copied items would not fit in the buffer, or if `dst_offset` is
+    /// beyond the initialized part, as that would leave uninitialized items in between.
+    pub fn copy_from_at<U>(&mut self, dst_offset: usize, src: &Buffer<U>)
     where
+        U: Initialize<Item = T::Item>,
         T::Item: Copy,
     {
+        let src = src.filled_part();
+        let items_initialized = self.initializer.items_initialized();
+
+        let end = dst_offset
+            .checked_add(src.len())
+            .expect("destination offset overflowed");
+        assert!(
+            end <= self.capacity(),
+            "cannot copy {} items to offset {}, beyond the end of the buffer ({} total)",
+            src.len(),
+            dst_offset,
+            self.capacity()
+        );
+        assert!(
+            dst_offset <= items_initialized,
+            "cannot copy to offset {}, beyond the initialized region ({} init)",
+            dst_offset,
+            items_initialized
+        );
+
         unsafe {
-            crate::fill_uninit_slice(self.unfilled_part_mut(), item);
-            self.assume_init_all();
+            self.initializer.all_uninit_mut()[dst_offset..end]
+                .copy_from_slice(crate::cast_init_to_uninit_slice(src));
+        }
+
+        self.initializer.items_initialized = core::cmp::max(items_initialized, end);
+
+        if dst_offset <= self.items_filled {
+            self.items_filled = core::cmp::max(self.items_filled, end);
         }
+
+        self.debug_assert_validity();
     }
-}
-impl<T> Buffer<T>
-where
-    T: Initialize<Item = u8>,
-{
+    /// Append as much as possible of the filled part of `src` to this buffer, returning the
+    /// number of items copied.
     #[inline]
-    pub fn fill_by_zeroing(&mut self) {
-        self.fill_by_repeating(0_u8);
+    pub fn copy_from_buffer<U>(&mut self, src: &Buffer<U>) -> usize
+    where
+        U: Initialize<Item = T::Item>,
+        T::Item: Copy,
+    {
+        let src = src.filled_part();
+        let count = core::cmp::min(src.len(), self.remaining());
+
+        self.append(&src[..count]);
+
+        count
     }
-}
-impl<'a> Buffer<AsUninit<&'a mut [u8]>> {
-    // TODO: Use a trait that makes the dynamic counter statically set to full.
-    #[inline]
-    pub fn from_slice_mut(slice: &'a mut [u8]) -> Self {
-        let mut initializer = BufferInitializer::new(slice);
+    /// Append a copy of `src_range` of the buffer itself, e.g. for back-references when
+    /// decompressing. The source range may overlap with the destination, in which case the copy
+    /// behaves as if the source was first copied to a temporary buffer, like `memmove`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `src_range` is not within the initialized part, or if the copy does not
+    /// fit in the unfilled part.
+    pub fn append_from_within_backing(&mut self, src_range: Range<usize>)
+    where
+        T::Item: Copy,
+    {
+        let items_initialized = self.initializer.items_initialized();
+
+        assert!(
+            src_range.start <= src_range.end && src_range.end <= items_initialized,
+            "source range {:?} is not within the initialized part of length {}",
+            src_range,
+            items_initialized
+        );
+        let count = src_range.end - src_range.start;
+        assert!(
+            count <= self.remaining(),
+            "cannot append {} items to a buffer with only {} items remaining",
+            count,
+            self.remaining()
+        );
+
         unsafe {
-            initializer.advance_to_end();
+            // SAFETY: The source range is initialized, and thus so will the destination be.
+            let dst = self.items_filled;
+            self.initializer
+                .all_uninit_mut()
+                .copy_within(src_range, dst);
+            self.assume_init(count);
         }
-        Self::from_initializer(initializer)
     }
-}
-impl<'a> Buffer<&'a mut [MaybeUninit<u8>]> {
-    #[inline]
-    pub fn from_uninit_slice_mut(slice: &'a mut [MaybeUninit<u8>]) -> Self {
-        Self::uninit(slice)
+    /// Append as much as possible of `slices` in order, stopping once the buffer is full. Returns
+    /// the total number of items copied.
+    pub fn append_vectored(&mut self, slices: &[&[T::Item]]) -> usize
+    where
+        T::Item: Copy,
+    {
+        let mut total = 0;
+
+        for slice in slices {
+            let count = core::cmp::min(slice.len(), self.remaining());
+            self.append(&slice[..count]);
+            total += count;
+
+            if count < slice.len() {
+                break;
+            }
+        }
+
+        total
     }
-}
+    /// Append as much as possible of `src` to the buffer, and advance `src` past the items that
+    /// were copied, so that repeated calls will drain it. Returns the number of items copied.
+    pub fn append_all_from(&mut self, src: &mut &[T::Item]) -> usize
+    where
+        T::Item: Copy,
+    {
+        let count = core::cmp::min(src.len(), self.remaining());
+        let (head, tail) = src.split_at(count);
 
-impl<'buffer, T> BufferRef<'buffer, T> {
-    #[inline]
-    pub fn items_filled(&self) -> usize {
-        self.inner.items_filled()
+        self.append(head);
+        *src = tail;
+
+        count
     }
+    /// Append the items of `iter` until either the iterator or the unfilled part is exhausted,
+    /// returning whether all items fit. Checking this may take one additional item from the
+    /// iterator, which is discarded.
+    pub fn try_extend<I>(&mut self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = T::Item>,
+        T::Item: Copy,
+    {
+        let mut iter = iter.into_iter();
+        let mut count = 0;
 
-    /// Reborrow the inner buffer, getting a buffer reference with a shorter lifetime.
-    #[inline]
-    pub fn by_ref(&mut self) -> BufferRef<'_, T> {
-        BufferRef { inner: self.inner }
+        unsafe {
+            // NOTE: The unfilled part goes first, so that no item is taken from the iterator
+            // once the buffer is full.
+            for (slot, item) in self.unfilled_part_mut().iter_mut().zip(&mut iter) {
+                *slot = MaybeUninit::new(item);
+                count += 1;
+            }
+            // SAFETY: The first `count` items of the unfilled part were initialized above.
+            self.assume_init(count);
+        }
+
+        iter.next().is_none()
     }
-}
+    /// Move as much as possible of the filled part of `src` to this buffer, returning the number
+    /// of items moved. The moved items are consumed from `src`, by moving the rest of its filled
+    /// part to the start, so that `src` can continue to be filled.
+    pub fn append_drain_from<U>(&mut self, src: &mut Buffer<U>) -> usize
+    where
+        U: Initialize<Item = T::Item>,
+        T::Item: Copy,
+    {
+        let count = self.copy_from_buffer(src);
+        let src_filled = src.items_filled;
 
-impl<'buffer, T> BufferRef<'buffer, T>
-where
-    T: Initialize,
-{
-    #[inline]
-    pub fn remaining(&self) -> usize {
-        self.inner.remaining()
+        src.filled_part_mut().copy_within(count..src_filled, 0);
+        src.items_filled = src_filled - count;
+        src.items_consumed = src.items_consumed.saturating_sub(count);
+
+        src.debug_assert_validity();
+
+        count
     }
-    #[inline]
-    pub fn unfilled_parts(&mut self) -> (&mut [T::Item], &mut [MaybeUninit<T::Item>]) {
-        self.inner.unfilled_parts_mut()
+    /// Move as much as possible of the filled part of this buffer to `dst`, e.g. to flush a read
+    /// buffer into a write buffer. This is the counterpart of
+    /// [`append_drain_from`](Self::append_drain_from), but the items that remain are moved to the
+    /// start using [`rotate_filled_left`](Self::rotate_filled_left), and thus the initialized part
+    /// shrinks accordingly. Returns the number of items moved.
+    pub fn drain_filled_to<U>(&mut self, dst: &mut Buffer<U>) -> usize
+    where
+        U: Initialize<Item = T::Item>,
+        T::Item: Copy,
+    {
+        let count = core::cmp::min(self.items_filled, dst.remaining());
+
+        dst.append(&self.filled_part()[..count]);
+        self.rotate_filled_left(count);
+
+        count
     }
-    /// Get a mutable and possibly-uninitialized reference to all of the buffer.
+    /// Append `slice` to the buffer if it fits entirely in the unfilled part, returning whether it
+    /// did. Unlike [`append`](Self::append), this never panics; if `slice` is too large, nothing
+    /// is appended.
+    #[inline]
+    pub fn append_if_fits(&mut self, slice: &[T::Item]) -> bool
+    where
+        T::Item: Copy,
+    {
+        if slice.len() > self.remaining() {
+            return false;
+        }
+        self.append(slice);
+
+        true
+    }
+    /// Append `slice` to the buffer only if it fits entirely in the unfilled part. Otherwise, an
+    /// [`ExactError`] describing how many items were needed is returned, and the buffer is left
+    /// unmodified.
+    #[inline]
+    pub fn append_exact(&mut self, slice: &[T::Item]) -> Result<(), ExactError>
+    where
+        T::Item: Copy,
+    {
+        let available = self.remaining();
+
+        if slice.len() > available {
+            return Err(ExactError {
+                needed: slice.len(),
+                available,
+            });
+        }
+        self.append(slice);
+
+        Ok(())
+    }
+    /// Append `slice` to the buffer only if it fits entirely, returning the offset within the
+    /// filled part where it was placed, e.g. for building an index of appended records. If it does
+    /// not fit, the number of missing items is returned instead, and the buffer is left
+    /// unmodified.
+    #[inline]
+    pub fn append_returning_offset(&mut self, slice: &[T::Item]) -> Result<usize, usize>
+    where
+        T::Item: Copy,
+    {
+        let offset = self.items_filled;
+
+        match self.append_exact(slice) {
+            Ok(()) => Ok(offset),
+            Err(ExactError { needed, available }) => Err(needed - available),
+        }
+    }
+    /// Append all of `pieces` separated by `separator`, e.g. to build delimited output directly
+    /// into the buffer. Either everything is appended, or if it does not fit, an [`ExactError`]
+    /// with the total length is returned and the filled part of the buffer is left unmodified.
+    /// Should the total length overflow, `needed` is reported as `usize::MAX`.
     ///
-    /// # Safety
+    /// The pieces are iterated over only once, so that single-pass iterators can be used. They
+    /// are appended for as long as they fit, and the remaining lengths are only summed up, after
+    /// which the filledness cursor is rolled back if not everything fit. The unfilled part may
+    /// thus have been overwritten even if an error is returned.
+    pub fn append_joined<'a, I>(
+        &mut self,
+        pieces: I,
+        separator: &[T::Item],
+    ) -> Result<(), ExactError>
+    where
+        I: IntoIterator<Item = &'a [T::Item]>,
+        T::Item: Copy + 'a,
+    {
+        let items_filled = self.items_filled;
+        let available = self.remaining();
+        let mut needed = Some(0_usize);
+
+        for (index, piece) in pieces.into_iter().enumerate() {
+            let separator: &[T::Item] = if index > 0 { separator } else { &[] };
+
+            needed = needed
+                .and_then(|needed| needed.checked_add(separator.len()))
+                .and_then(|needed| needed.checked_add(piece.len()));
+
+            if matches!(needed, Some(needed) if needed <= available) {
+                self.append(separator);
+                self.append(piece);
+            }
+        }
+
+        let needed = needed.unwrap_or(usize::MAX);
+
+        if needed > available {
+            // NOTE: The items that were appended remain initialized, only the filledness cursor
+            // is rolled back.
+            self.items_filled = items_filled;
+            self.debug_assert_validity();
+
+            return Err(ExactError { needed, available });
+        }
+
+        Ok(())
+    }
+    #[inline]
+    pub fn advance(&mut self, count: usize) {
+        assert!(
+            self.initializer
+                .items_initialized()
+                .wrapping_sub(self.items_filled)
+                >= count,
+            "advancing filledness cursor beyond the initialized region ({} + {} = {} filled > {} init)",
+            self.items_filled,
+            count,
+            self.items_filled + count,
+            self.initializer.items_initialized,
+        );
+        self.items_filled = self.items_filled.wrapping_add(count);
+    }
+    /// Advance the filledness cursor by `count` items, like [`advance`](Self::advance), but
+    /// return an error rather than panicking if that would go beyond the initialized part. The
+    /// buffer is left untouched on failure.
+    #[doc(alias = "advance_checked")]
+    #[inline]
+    pub fn try_advance(&mut self, count: usize) -> Result<(), AdvanceError> {
+        let available = self
+            .initializer
+            .items_initialized()
+            .wrapping_sub(self.items_filled);
+
+        if count > available {
+            return Err(AdvanceError::BeyondInitialized { count, available });
+        }
+        self.items_filled = self.items_filled.wrapping_add(count);
+
+        Ok(())
+    }
+    #[inline]
+    pub fn advance_to_init_part(&mut self) {
+        self.items_filled = self.initializer.items_initialized;
+    }
+    /// Advance the filledness cursor over the initialized-but-unfilled part (as returned by
+    /// [`unfilled_init_part`](Self::unfilled_init_part)), but only if `f` approves of its
+    /// contents. This allows already initialized data to be inspected, before it is accepted as
+    /// filled.
+    #[inline]
+    pub fn advance_over_gap_if<F>(&mut self, f: F)
+    where
+        F: FnOnce(&[T::Item]) -> bool,
+    {
+        if f(self.unfilled_init_part()) {
+            self.advance_to_init_part();
+        }
+    }
+    /// Discard the filled part of the buffer, by moving the initialized-but-unfilled part to the
+    /// start of the buffer, and then resetting the filledness cursor to zero. The initialization
+    /// cursor is moved back accordingly, to the end of the moved items.
     ///
-    /// The caller must not allow safe code to de-initialize the resulting slice.
+    /// This is the classic compaction operation for read buffers, which allows more items to be
+    /// appended once the filled items have been consumed. If nothing is filled, this is a no-op.
     #[inline]
-    pub unsafe fn unfilled_mut(&mut self) -> &mut [MaybeUninit<T::Item>] {
-        self.inner.unfilled_part_mut()
+    pub fn compact(&mut self)
+    where
+        T::Item: Copy,
+    {
+        let items_filled = self.items_filled;
+        let items_initialized = self.initializer.items_initialized;
+
+        if items_filled == 0 {
+            return;
+        }
+
+        self.initializer
+            .init_part_mut()
+            .copy_within(items_filled..items_initialized, 0);
+
+        self.items_filled = 0;
+        self.items_consumed = 0;
+        self.initializer.items_initialized = items_initialized - items_filled;
+
+        self.debug_assert_validity();
     }
-    /// Advance the counter of the number of items filled.
+    /// Drop the first `amt` filled items, moving the remaining filled items to the start of the
+    /// buffer. Both the filled and the initialized parts shrink by `amt` items, and the items in
+    /// the unfilled part are left untouched.
     ///
-    /// The number of items that are initialized is also updated accordingly, so that the number of
-    /// items initialized is always greater than or equal to the number of items filled.
+    /// Unlike [`compact`](Self::compact), this does not require the filled part to have been
+    /// consumed first, and the items being moved may overlap with their destination.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The caller must uphold the initialization invariant.
+    /// This will panic if `amt` is larger than the number of filled items.
+    pub fn rotate_filled_left(&mut self, amt: usize)
+    where
+        T::Item: Copy,
+    {
+        let items_filled = self.items_filled;
+
+        assert!(
+            amt <= items_filled,
+            "cannot rotate away {} items when only {} items are filled",
+            amt,
+            items_filled
+        );
+
+        if amt == 0 {
+            return;
+        }
+
+        self.initializer
+            .init_part_mut()
+            .copy_within(amt..items_filled, 0);
+
+        self.items_filled -= amt;
+        self.items_consumed = self.items_consumed.saturating_sub(amt);
+        self.initializer.items_initialized -= amt;
+
+        self.debug_assert_validity();
+    }
+    /// Shrink both the filled and the initialized parts to at most `new_len` items, discarding
+    /// the filled items after it, and forgetting that the items after it were initialized.
+    ///
+    /// Merely moving the filledness cursor back would keep the items that were initialized, so
+    /// that they could be reused without being initialized again. This instead leaves a clean
+    /// uninitialized region after `new_len`, e.g. when that region is to be filled again and
+    /// stale data must not be reachable via [`unfilled_init_part`](Self::unfilled_init_part).
     #[inline]
-    pub unsafe fn advance(&mut self, count: usize) {
-        self.inner.assume_init(count)
+    pub fn shrink_filled_to(&mut self, new_len: usize) {
+        self.items_filled = core::cmp::min(self.items_filled, new_len);
+        self.items_consumed = core::cmp::min(self.items_consumed, new_len);
+        self.initializer.items_initialized =
+            core::cmp::min(self.initializer.items_initialized, new_len);
+
+        self.debug_assert_validity();
     }
-    /// Advance the counter of the number of items filled, and the number of items initialized, to
-    /// the end of the buffer.
+    /// Forget that the items past index `n` are initialized, without writing anything, e.g. so
+    /// that a tail which was speculatively initialized will have to be initialized again before
+    /// being used. The initialized part is never shrunk below the filled part.
+    #[inline]
+    pub fn forget_init_past(&mut self, n: usize) {
+        let new_items_initialized = core::cmp::max(self.items_filled, n);
+
+        if new_items_initialized < self.initializer.items_initialized {
+            self.initializer.items_initialized = new_items_initialized;
+        }
+
+        self.debug_assert_validity();
+    }
+    // TODO: Method for increasing the items filled, but not the items initialized?
+    /// Increment the counter that marks the progress of filling, as well as the initialization
+    /// progress, `count` items.
     ///
     /// # Safety
     ///
-    /// The caller must uphold the initialization invariant.
+    /// This does not initialize nor fill anything, and it is hence up to the user to ensure that
+    /// no uninitialized items are marked initialized.
+    pub unsafe fn assume_init(&mut self, count: usize) {
+        self.items_filled += count;
+        self.initializer.items_initialized =
+            core::cmp::max(self.items_filled, self.initializer.items_initialized);
+
+        self.debug_assert_validity();
+    }
+    /// Set the filledness cursor to `filled` items, increasing the initialization cursor if
+    /// needed. Unlike [`assume_init`](Self::assume_init), this is absolute rather than relative,
+    /// which is meant for low-level integrations that know the new number of filled items
+    /// directly, e.g. after a system call filling the buffer from the start.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the first `filled` items are initialized, and that `filled`
+    /// does not exceed the capacity of the buffer.
     #[inline]
-    pub unsafe fn advance_all(&mut self) {
-        self.inner.assume_init_all();
+    pub unsafe fn set_filled(&mut self, filled: usize) {
+        debug_assert!(
+            filled <= self.capacity(),
+            "cannot set filled to {} items in a buffer of capacity {}",
+            filled,
+            self.capacity()
+        );
+
+        self.items_filled = filled;
+        self.items_consumed = core::cmp::min(self.items_consumed, filled);
+        self.initializer.items_initialized =
+            core::cmp::max(self.initializer.items_initialized, filled);
+
+        self.debug_assert_validity();
     }
+    /// Mark the buffer as fully filled and initialized, without actually filling the buffer.
+    ///
+    /// # Safety
+    ///
+    /// For this to be safe, the caller must ensure that every single item in the slice that the
+    /// buffer wraps, is initialized.
     #[inline]
-    pub fn revert_to_start(&mut self) {
-        self.inner.revert_to_start()
+    pub unsafe fn assume_init_all(&mut self) {
+        self.items_filled = self.capacity();
+        self.initializer.items_initialized = self.capacity();
     }
     #[inline]
     pub fn fill_by_repeating(&mut self, item: T::Item)
     where
         T::Item: Copy,
     {
-        self.inner.fill_by_repeating(item)
+        unsafe {
+            crate::fill_uninit_slice(self.unfilled_part_mut(), item);
+            self.assume_init_all();
+        }
+    }
+    /// Append copies of `pad` until the number of items filled is a multiple of `block`, e.g. to
+    /// pad a record to the next block boundary. If the filled part is already aligned, this does
+    /// nothing. If the padding does not fit, the number of missing items is returned, and the
+    /// buffer is left unmodified.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `block` is zero.
+    pub fn pad_to_multiple_of(&mut self, block: usize, pad: T::Item) -> Result<(), usize>
+    where
+        T::Item: Copy,
+    {
+        assert_ne!(block, 0, "cannot pad to a multiple of zero");
+
+        let padding = (block - self.items_filled % block) % block;
+        let remaining = self.remaining();
+
+        if padding > remaining {
+            return Err(padding - remaining);
+        }
+
+        unsafe {
+            crate::fill_uninit_slice(&mut self.unfilled_part_mut()[..padding], pad);
+            self.assume_init(padding);
+        }
+
+        Ok(())
     }
+    /// Fill the unfilled part of the buffer with the default value of the item type, marking the
+    /// whole buffer as filled. This is the general counterpart of
+    /// [`fill_by_zeroing`](Self::fill_by_zeroing) for item types other than bytes; for most
+    /// primitive types, the default value is zero anyway.
     #[inline]
-    pub fn append(&mut self, slice: &[T::Item])
+    pub fn fill_by_default(&mut self)
+    where
+        T::Item: Default + Copy,
+    {
+        self.fill_by_repeating(T::Item::default())
+    }
+    /// Fill the unfilled part of the buffer with items returned by `f`, called once per item in
+    /// order, marking the whole buffer as filled.
+    ///
+    /// If `f` panics, only the items that were written before that will be filled.
+    pub fn fill_remaining_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T::Item,
+    {
+        struct Guard<'a, T>
+        where
+            T: Initialize,
+        {
+            buffer: &'a mut Buffer<T>,
+            written: usize,
+        }
+        impl<T> Drop for Guard<'_, T>
+        where
+            T: Initialize,
+        {
+            fn drop(&mut self) {
+                // SAFETY: The first `written` unfilled items have been initialized, also when `f`
+                // has panicked.
+                unsafe { self.buffer.assume_init(self.written) }
+            }
+        }
+
+        let mut guard = Guard {
+            buffer: self,
+            written: 0,
+        };
+        // SAFETY: Only initialized items are written to the unfilled part.
+        let unfilled = unsafe { guard.buffer.unfilled_part_mut() };
+
+        for slot in unfilled {
+            *slot = MaybeUninit::new(f());
+            guard.written += 1;
+        }
+    }
+    /// Fill the unfilled part of the buffer by repeatedly copying `pattern`, marking the whole
+    /// buffer as filled. The last copy of the pattern is cut off, if it does not fit entirely.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `pattern` is empty.
+    #[doc(alias = "fill_by_tiling")]
+    pub fn fill_remaining_cycled(&mut self, pattern: &[T::Item])
     where
         T::Item: Copy,
     {
-        self.inner.append(slice)
+        assert!(
+            !pattern.is_empty(),
+            "cannot fill a buffer using an empty pattern"
+        );
+
+        unsafe {
+            let pattern = crate::cast_init_to_uninit_slice(pattern);
+
+            for chunk in self.unfilled_part_mut().chunks_mut(pattern.len()) {
+                chunk.copy_from_slice(&pattern[..chunk.len()]);
+            }
+            self.assume_init_all();
+        }
     }
 }
-impl<T> BufferRef<'_, T>
+impl<T> Buffer<T>
 where
     T: Initialize<Item = u8>,
 {
     #[inline]
     pub fn fill_by_zeroing(&mut self) {
-        self.inner.fill_by_zeroing()
+        self.fill_by_repeating(0_u8);
     }
-}
-
-impl<T> fmt::Debug for Buffer<T>
-where
-    T: Initialize,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let ptr = self.initializer().all_uninit().as_ptr();
-        let items_init = self.initializer().items_initialized();
-        let items_filled = self.items_filled();
-        let total = self.capacity();
+    /// Fill the unfilled part of the buffer with an arithmetic ramp, starting at `start` and
+    /// increasing by `step` for each item, and mark the buffer as full. This is mainly useful for
+    /// generating recognizable patterns when debugging memory corruption or alignment issues.
+    ///
+    /// The ramp wraps around at 255, so that e.g. `start = 254` and `step = 1` will produce `254,
+    /// 255, 0, 1, ...`.
+    #[inline]
+    pub fn fill_ramp(&mut self, start: u8, step: u8) {
+        unsafe {
+            let mut value = start;
 
-        if f.alternate() {
-            let init_percentage = items_init as f64 / total as f64 * 100.0;
-            let filled_percentage = items_filled as f64 / total as f64 * 100.0;
-            write!(
-                f,
-                "[buffer at {:?}, {} B filled ({:.1}%), {} B init ({:.1}%), {} B total]",
-                ptr, items_filled, filled_percentage, items_init, init_percentage, total
-            )
-        } else {
-            write!(
-                f,
-                "[buffer at {:?}, {}/{}/{}]",
-                ptr, items_filled, items_init, total
-            )
+            for item in self.unfilled_part_mut() {
+                *item = MaybeUninit::new(value);
+                value = value.wrapping_add(step);
+            }
+            self.assume_init_all();
         }
     }
-}
+    /// Get the whole backing memory of the buffer as possibly-uninitialized bytes, regardless of
+    /// how much is filled or initialized, e.g. for setting up memory mappings or DMA descriptors.
+    #[inline]
+    pub fn all_bytes_maybe_uninit(&self) -> &[MaybeUninit<u8>] {
+        self.initializer.all_uninit()
+    }
+    /// Take a length-prefixed frame from the start of the [`readable`](Self::readable) part,
+    /// where the length is a big-endian integer of `prefix_len` bytes. If the whole frame has
+    /// been filled, the prefix and the payload are consumed, and the payload is returned.
+    /// Otherwise, `None` is returned and nothing is consumed, so that more data can be filled.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `prefix_len` is zero, or larger than the size of `usize`.
+    pub fn try_take_frame(&mut self, prefix_len: usize) -> Option<&[u8]> {
+        assert!(
+            prefix_len > 0 && prefix_len <= core::mem::size_of::<usize>(),
+            "a length prefix of {} bytes is not supported",
+            prefix_len
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let readable = self.readable();
+        let prefix = readable.get(..prefix_len)?;
+        let payload_len = prefix
+            .iter()
+            .fold(0_usize, |len, &byte| (len << 8) | usize::from(byte));
 
-    #[test]
-    fn basic_buffer_ops() {
-        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
-        assert_eq!(buffer.capacity(), 32);
-        assert_eq!(buffer.remaining(), 32);
-        assert!(buffer.is_empty());
-        assert!(!buffer.is_full());
-        assert!(buffer.initializer().is_completely_uninit());
-        assert!(!buffer.initializer().is_completely_init());
-        assert_eq!(buffer.initializer().items_initialized(), 0);
-        assert_eq!(buffer.initializer().remaining(), 32);
-        assert_eq!(buffer.filled_part(), &[]);
-        assert_eq!(buffer.unfilled_part().len(), 32);
+        if readable.len() - prefix_len < payload_len {
+            return None;
+        }
+
+        let start = self.items_consumed + prefix_len;
+        self.consume(prefix_len + payload_len);
+
+        // NOTE: Consuming everything may reset the filledness cursor, but the payload will still
+        // be part of the initialized part.
+        Some(&self.initializer.init_part()[start..start + payload_len])
+    }
+    /// Take a length-prefixed frame from the start of the [`readable`](Self::readable) part, like
+    /// [`try_take_frame`](Self::try_take_frame), but return an owned copy of the payload and move
+    /// the remaining filled bytes to the start of the buffer, so that more data can be filled.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `prefix_width` is larger than the size of `usize`.
+    #[cfg(feature = "alloc")]
+    pub fn verify_and_split_frame(&mut self, prefix_width: PrefixWidth) -> Option<Vec<u8>> {
+        let payload = self.try_take_frame(prefix_width.byte_len())?.to_vec();
+        self.rotate_filled_left(self.items_consumed);
+
+        Some(payload)
+    }
+    /// Interpret the filled part of the buffer as UTF-8.
+    #[doc(alias = "filled_as_str")]
+    #[inline]
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.filled_part())
+    }
+    /// Interpret the filled part of the buffer as UTF-8, without validating it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the filled part is valid UTF-8.
+    #[doc(alias = "filled_as_str_unchecked")]
+    #[inline]
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        core::str::from_utf8_unchecked(self.filled_part())
+    }
+    /// Assert that the filled part is equal to `expected`, panicking with the offset of the first
+    /// difference together with the surrounding bytes in hexadecimal otherwise. This is meant for
+    /// tests, where the default output of `assert_eq!` can be unwieldy for large buffers.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the filled part is not equal to `expected`.
+    #[cfg(feature = "test-util")]
+    #[track_caller]
+    pub fn assert_filled_eq(&self, expected: &[u8]) {
+        const CONTEXT: usize = 8;
+
+        let filled = self.filled_part();
+
+        let offset = match filled.iter().zip(expected).position(|(a, b)| a != b) {
+            Some(offset) => offset,
+            None if filled.len() == expected.len() => return,
+            None => core::cmp::min(filled.len(), expected.len()),
+        };
+        let start = offset.saturating_sub(CONTEXT);
+        let context_end = |slice: &[u8]| core::cmp::min(offset + CONTEXT, slice.len());
+        let filled_context = &filled[start..context_end(filled)];
+        let expected_context = &expected[start..context_end(expected)];
+
+        panic!(
+            "filled part differs from the expected bytes at offset {} (filled {} bytes, expected {} bytes)\n  filled[{}..]: {:02x?}\nexpected[{}..]: {:02x?}",
+            offset,
+            filled.len(),
+            expected.len(),
+            start,
+            filled_context,
+            start,
+            expected_context,
+        );
+    }
+    /// Encode `data` as padded base64, appending it directly to the buffer. The length of the
+    /// encoded output is computed beforehand, so that either all of it is appended, or if it does
+    /// not fit, an [`ExactError`] is returned and the buffer is left unmodified.
+    #[cfg(feature = "base64")]
+    pub fn append_base64(&mut self, data: &[u8]) -> Result<(), ExactError> {
+        use base64::Engine as _;
+
+        let needed = base64::encoded_len(data.len(), true)
+            .expect("the length of the base64 encoded data overflowed usize");
+        let available = self.remaining();
+
+        if needed > available {
+            return Err(ExactError { needed, available });
+        }
+
+        let end = self.items_filled + needed;
+        let items_initialized = self.initializer.items_initialized();
+
+        if items_initialized < end {
+            self.initializer
+                .partially_zero_uninit_part(end - items_initialized);
+        }
+
+        let count = base64::engine::general_purpose::STANDARD
+            .encode_slice(data, &mut self.unfilled_init_part_mut()[..needed])
+            .expect("the base64 output length was checked beforehand");
+        self.advance(count);
+
+        Ok(())
+    }
+    /// Decode the [`readable`](Self::readable) part of the buffer as padded base64 in place, e.g.
+    /// once an encoded payload has been received. The decoded bytes are appended to the filled
+    /// part, and the encoded bytes are consumed, so that only the decoded bytes remain readable.
+    ///
+    /// The decoded length is computed from the encoded bytes beforehand, so that if it does not
+    /// fit, [`OutputSliceTooSmall`] is returned and the buffer is left unmodified. If the encoded
+    /// bytes are invalid, the filled part is left unmodified as well, although the unfilled part may
+    /// have been initialized.
+    ///
+    /// [`OutputSliceTooSmall`]: base64::DecodeSliceError::OutputSliceTooSmall
+    #[cfg(feature = "base64")]
+    pub fn decode_base64_in_place(&mut self) -> Result<(), base64::DecodeSliceError> {
+        use base64::{DecodeError, DecodeSliceError, Engine as _};
+
+        let encoded_len = self.items_filled - self.items_consumed;
+        let (quads, rest) = (encoded_len / 4, encoded_len % 4);
+
+        if rest != 0 {
+            return Err(DecodeSliceError::DecodeError(DecodeError::InvalidLength(
+                encoded_len,
+            )));
+        }
+
+        let padding = self
+            .readable()
+            .iter()
+            .rev()
+            .take(2)
+            .take_while(|&&byte| byte == b'=')
+            .count();
+        let needed = quads * 3 - padding;
+
+        if needed > self.remaining() {
+            return Err(DecodeSliceError::OutputSliceTooSmall);
+        }
+
+        let end = self.items_filled + needed;
+        let items_initialized = self.initializer.items_initialized();
+
+        if items_initialized < end {
+            self.initializer
+                .partially_zero_uninit_part(end - items_initialized);
+        }
+
+        let (filled, unfilled) = self
+            .initializer
+            .init_part_mut()
+            .split_at_mut(self.items_filled);
+        let count = base64::engine::general_purpose::STANDARD
+            .decode_slice(&filled[self.items_consumed..], &mut unfilled[..needed])?;
+        self.advance(count);
+        self.consume(encoded_len);
+
+        Ok(())
+    }
+    /// Fill the unfilled part of the buffer with random bytes from the operating system, e.g. for
+    /// generating nonces or keys. If an error occurs, the buffer is left as filled as before.
+    #[cfg(feature = "getrandom")]
+    pub fn fill_by_random(&mut self) -> Result<(), getrandom::Error> {
+        unsafe {
+            getrandom::getrandom_uninit(self.unfilled_part_mut())?;
+            self.assume_init_all();
+        }
+        Ok(())
+    }
+    /// Get an `iovec` pointing to the unfilled part of the buffer, which may be uninitialized,
+    /// e.g. for passing directly to `read(2)` or `readv(2)`. After the system call has written to
+    /// it, the number of bytes read can be marked as filled using [`assume_init`].
+    ///
+    /// Note that the returned `iovec` only contains a raw pointer, and is thus not tied to the
+    /// lifetime of the buffer. It must not be used after the buffer has been moved, dropped, or
+    /// modified in any other way, including advancing it, as it would then either dangle or
+    /// point to what may have become part of the filled part.
+    ///
+    /// [`assume_init`]: Self::assume_init
+    #[cfg(feature = "libc")]
+    #[inline]
+    pub fn as_iovec_mut(&mut self) -> libc::iovec {
+        // SAFETY: The unfilled part is only converted to a raw pointer, and nothing is written to
+        // it here.
+        let unfilled_part = unsafe { self.unfilled_part_mut() };
+
+        libc::iovec {
+            iov_base: unfilled_part.as_mut_ptr().cast(),
+            iov_len: unfilled_part.len(),
+        }
+    }
+}
+
+macro_rules! put_integer_methods {
+    ($($integer:ty => $le:ident, $be:ident;)*) => {
+        $(
+            #[doc = concat!("Append a `", stringify!($integer), "` in little-endian byte order.")]
+            #[doc = ""]
+            #[doc = "Either all of its bytes are appended, or if they do not fit, an [`ExactError`]"]
+            #[doc = "is returned and the buffer is left unmodified."]
+            #[inline]
+            pub fn $le(&mut self, value: $integer) -> Result<(), ExactError> {
+                self.append_exact(&value.to_le_bytes())
+            }
+            #[doc = concat!("Append a `", stringify!($integer), "` in big-endian byte order.")]
+            #[doc = ""]
+            #[doc = "Either all of its bytes are appended, or if they do not fit, an [`ExactError`]"]
+            #[doc = "is returned and the buffer is left unmodified."]
+            #[inline]
+            pub fn $be(&mut self, value: $integer) -> Result<(), ExactError> {
+                self.append_exact(&value.to_be_bytes())
+            }
+        )*
+    };
+}
+impl<T> Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    put_integer_methods! {
+        u16 => put_u16_le, put_u16_be;
+        u32 => put_u32_le, put_u32_be;
+        u64 => put_u64_le, put_u64_be;
+        u128 => put_u128_le, put_u128_be;
+        i16 => put_i16_le, put_i16_be;
+        i32 => put_i32_le, put_i32_be;
+        i64 => put_i64_le, put_i64_be;
+        i128 => put_i128_le, put_i128_be;
+    }
+}
+#[cfg(feature = "std")]
+impl<T> Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    /// Read from `reader` into the unfilled part of the buffer, until either the number of items
+    /// filled reaches `high_watermark`, the buffer is full, or the reader reaches EOF. This allows
+    /// the consumer to cap how much data is buffered, even if there is more capacity left.
+    ///
+    /// Since [`Read`](std::io::Read) requires initialized buffers, the uninitialized part is
+    /// zeroed before reading, but only as much as needed. Returns the number of bytes read.
+    pub fn read_from_bounded<R>(
+        &mut self,
+        reader: &mut R,
+        high_watermark: usize,
+    ) -> std::io::Result<usize>
+    where
+        R: std::io::Read + ?Sized,
+    {
+        let end = core::cmp::min(high_watermark, self.capacity());
+        let mut total = 0;
+
+        while self.items_filled < end {
+            let limit = end - self.items_filled;
+            let items_initialized = self.initializer.items_initialized();
+
+            if items_initialized < end {
+                self.initializer
+                    .partially_zero_uninit_part(end - items_initialized);
+            }
+
+            let count = match reader.read(&mut self.unfilled_init_part_mut()[..limit]) {
+                Ok(0) => break,
+                Ok(count) => count,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            self.advance(count);
+            total += count;
+        }
+
+        Ok(total)
+    }
+    /// Read from `reader` until either the buffer is full, or the reader reaches EOF, calling
+    /// `on_idle` every time the reader would block instead of returning the error. This allows
+    /// e.g. yielding to other tasks in a single-threaded cooperative scheduler, while waiting for
+    /// a non-blocking reader. Returns the number of bytes read.
+    pub fn read_from_yielding<R, F>(
+        &mut self,
+        reader: &mut R,
+        mut on_idle: F,
+    ) -> std::io::Result<usize>
+    where
+        R: std::io::Read + ?Sized,
+        F: FnMut(),
+    {
+        let start = self.items_filled;
+
+        loop {
+            match self.read_from_bounded(reader, self.capacity()) {
+                // NOTE: Whatever was read before the reader would block, has already been marked
+                // as filled, so the total can simply be derived from the filledness cursor.
+                Ok(_) => return Ok(self.items_filled - start),
+                Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => on_idle(),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+    /// Read exactly `count` more items from `reader` into the unfilled part of the buffer,
+    /// retrying on interruptions. The reader will never be asked for more than what is needed.
+    ///
+    /// If the reader reaches EOF before `count` items have been read, an error of kind
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) is returned, and the items that were
+    /// read are kept as filled.
+    ///
+    /// To fill the buffer completely, like [`Read::read_exact`](std::io::Read::read_exact) would,
+    /// pass [`remaining`](Self::remaining) as `count`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `count` is larger than the number of items remaining.
+    pub fn fill_exact_from_reader<R>(&mut self, reader: &mut R, count: usize) -> std::io::Result<()>
+    where
+        R: std::io::Read + ?Sized,
+    {
+        assert!(
+            count <= self.remaining(),
+            "cannot read {} items into a buffer with only {} items remaining",
+            count,
+            self.remaining()
+        );
+
+        let items_read = self.read_from_bounded(reader, self.items_filled + count)?;
+
+        if items_read < count {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "reader reached EOF before the requested number of items could be read",
+            ));
+        }
+
+        Ok(())
+    }
+    /// Reuse the buffer for fresh data, by discarding the filled part and then reading from
+    /// `reader` until either the buffer is full, or the reader reaches EOF. The initialized part
+    /// is kept, so previously initialized memory can be read into directly without zeroing it
+    /// again. Returns the number of bytes read.
+    pub fn overwrite_from<R>(&mut self, reader: &mut R) -> std::io::Result<usize>
+    where
+        R: std::io::Read + ?Sized,
+    {
+        self.items_filled = 0;
+        self.items_consumed = 0;
+        self.read_from_bounded(reader, self.capacity())
+    }
+    /// Temporarily view the buffer as a [`BorrowedBuf`](std::io::BorrowedBuf), e.g. to pass its
+    /// unfilled part to [`Read::read_buf`](std::io::Read::read_buf). The `BorrowedBuf` covers the
+    /// whole buffer, with its filled part set to that of this buffer. Once `f` returns, the
+    /// filled and initialized parts of this buffer are updated accordingly.
+    ///
+    /// Note that `BorrowedBuf` only tracks whether the buffer is _fully_ initialized, and thus
+    /// any initialized-but-unfilled items will not be known to it.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `f` replaces the `BorrowedBuf` with one that points to different
+    /// memory.
+    #[cfg(feature = "nightly")]
+    pub fn with_borrowed_buf<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut std::io::BorrowedBuf<'_>) -> R,
+    {
+        let is_completely_init = self.initializer.is_completely_init();
+        let items_filled = self.items_filled;
+
+        let (ret, new_items_filled, new_is_completely_init) = {
+            let all = unsafe { self.initializer.all_uninit_mut() };
+            let (all_ptr, all_len) = (all.as_ptr(), all.len());
+
+            let mut borrowed_buf = std::io::BorrowedBuf::from(all);
+
+            // SAFETY: The filled part is always initialized, and if the initializer is completely
+            // initialized, then so is the whole buffer.
+            unsafe {
+                if is_completely_init {
+                    borrowed_buf.set_init();
+                }
+                borrowed_buf.unfilled().advance(items_filled);
+            }
+
+            let ret = f(&mut borrowed_buf);
+
+            assert!(
+                borrowed_buf.filled().as_ptr() as *const MaybeUninit<u8> == all_ptr
+                    && borrowed_buf.capacity() == all_len,
+                "the BorrowedBuf was replaced with one pointing to different memory"
+            );
+
+            (ret, borrowed_buf.len(), borrowed_buf.is_init())
+        };
+
+        self.items_filled = new_items_filled;
+        self.items_consumed = core::cmp::min(self.items_consumed, new_items_filled);
+
+        if new_is_completely_init {
+            // SAFETY: The BorrowedBuf points to the same memory, and has verified that all of it
+            // is initialized.
+            unsafe { self.initializer.advance_to_end() }
+        } else {
+            self.initializer.items_initialized =
+                core::cmp::max(self.initializer.items_initialized, new_items_filled);
+        }
+        self.debug_assert_validity();
+
+        ret
+    }
+    /// Read from `reader` into the unfilled part of the buffer, using
+    /// [`Read::read_buf`](std::io::Read::read_buf), so that readers supporting it can write into
+    /// uninitialized memory directly, without it being zeroed first.
+    ///
+    /// Since `BorrowedBuf` can only track whether it is _fully_ initialized, an
+    /// initialized-but-unfilled part is read into on its own if there is one, so that it is not
+    /// zeroed again by readers that require initialized memory. Otherwise, the whole unfilled
+    /// part is read into.
+    #[cfg(feature = "nightly")]
+    pub fn read_buf_from<R>(&mut self, reader: &mut R) -> std::io::Result<()>
+    where
+        R: std::io::Read + ?Sized,
+    {
+        let reusable_init_len = self.reusable_init_len();
+
+        let (result, count, init_region_len) = {
+            // SAFETY: The BorrowedBuf never de-initializes anything.
+            let unfilled_part = unsafe { self.unfilled_part_mut() };
+            let region = if reusable_init_len > 0 {
+                &mut unfilled_part[..reusable_init_len]
+            } else {
+                unfilled_part
+            };
+            let region_len = region.len();
+            let mut borrowed_buf = std::io::BorrowedBuf::from(region);
+
+            if reusable_init_len > 0 {
+                // SAFETY: The region is exactly the initialized-but-unfilled part.
+                unsafe { borrowed_buf.set_init() };
+            }
+
+            let result = reader.read_buf(borrowed_buf.unfilled());
+            let is_init = borrowed_buf.is_init();
+
+            (result, borrowed_buf.len(), is_init.then_some(region_len))
+        };
+
+        let start = self.items_filled;
+        // SAFETY: The BorrowedBuf guarantees that its filled part has been initialized.
+        unsafe { self.assume_init(count) };
+
+        if let Some(region_len) = init_region_len {
+            self.initializer.items_initialized =
+                core::cmp::max(self.initializer.items_initialized, start + region_len);
+        }
+        self.debug_assert_validity();
+
+        result
+    }
+    /// Split the buffer into a reader of the filled part, and a writer to the unfilled part. Since
+    /// these point to disjoint memory, both can be used simultaneously, e.g. when relaying
+    /// previously buffered data, while buffering new data.
+    ///
+    /// Items written by the writer are immediately marked as filled, but will not be visible to
+    /// the reader, which only covers the filled part at the time of the split.
+    pub fn split(&mut self) -> (FilledReader<'_>, UninitWriter<'_>) {
+        let (all_ptr, all_len) = unsafe {
+            let all = self.initializer.all_uninit_mut();
+
+            (all.as_mut_ptr(), all.len())
+        };
+
+        unsafe {
+            self.debug_assert_validity();
+
+            let filled_base_ptr = all_ptr as *const u8;
+            let filled_len = self.items_filled;
+
+            let unfilled_base_ptr = all_ptr.add(self.items_filled);
+            let unfilled_len = all_len.wrapping_sub(self.items_filled);
+
+            let filled = core::slice::from_raw_parts(filled_base_ptr, filled_len);
+            let unfilled = core::slice::from_raw_parts_mut(unfilled_base_ptr, unfilled_len);
+
+            (
+                FilledReader { filled },
+                UninitWriter {
+                    unfilled,
+                    items_written: 0,
+                    items_filled: &mut self.items_filled,
+                    items_initialized: &mut self.initializer.items_initialized,
+                },
+            )
+        }
+    }
+}
+/// Items that do not fit in the unfilled part are silently discarded. Use
+/// [`Buffer::try_extend`] to find out whether everything fit.
+impl<T> Extend<u8> for Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let _ = self.try_extend(iter);
+    }
+}
+/// Items that do not fit in the unfilled part are silently discarded. Use
+/// [`Buffer::try_extend`] to find out whether everything fit.
+impl<'a, T> Extend<&'a u8> for Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a u8>,
+    {
+        let _ = self.try_extend(iter.into_iter().copied());
+    }
+}
+impl<T> fmt::Write for Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    /// Append the UTF-8 bytes of `s`, failing without appending anything if they do not all fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.append_exact(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.write_str(c.encode_utf8(&mut [0_u8; 4]))
+    }
+}
+#[cfg(feature = "std")]
+impl<T> std::io::Read for Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    /// Read from the [`readable`](Buffer::readable) part, consuming what was read.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = core::cmp::min(buf.len(), self.readable().len());
+        buf[..count].copy_from_slice(&self.readable()[..count]);
+        self.consume(count);
+
+        Ok(count)
+    }
+}
+#[cfg(feature = "std")]
+impl<T> std::io::BufRead for Buffer<T>
+where
+    T: Initialize<Item = u8>,
+{
+    /// Get the [`readable`](Buffer::readable) part. Since the buffer does not own a reader of
+    /// its own, this will never fill the buffer, and an empty slice thus means EOF.
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.readable())
+    }
+    fn consume(&mut self, amt: usize) {
+        Buffer::consume(self, amt)
+    }
+}
+/// A reader of the filled part of a buffer, obtained from [`Buffer::split`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct FilledReader<'a> {
+    filled: &'a [u8],
+}
+#[cfg(feature = "std")]
+impl FilledReader<'_> {
+    /// Get the filled items that have not yet been read.
+    #[inline]
+    pub fn remaining_part(&self) -> &[u8] {
+        self.filled
+    }
+}
+#[cfg(feature = "std")]
+impl std::io::Read for FilledReader<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.filled.read(buf)
+    }
+}
+/// A writer to the unfilled part of a buffer, obtained from [`Buffer::split`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct UninitWriter<'a> {
+    // NOTE: This may overlap with the initialized part, and must hence never be used to
+    // de-initialize anything.
+    unfilled: &'a mut [MaybeUninit<u8>],
+    items_written: usize,
+    items_filled: &'a mut usize,
+    items_initialized: &'a mut usize,
+}
+#[cfg(feature = "std")]
+impl UninitWriter<'_> {
+    /// Get the number of items that can still be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.unfilled.len() - self.items_written
+    }
+}
+#[cfg(feature = "std")]
+impl std::io::Write for UninitWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let count = core::cmp::min(buf.len(), self.remaining());
+        let end = self.items_written + count;
+
+        self.unfilled[self.items_written..end]
+            .copy_from_slice(crate::cast_init_to_uninit_slice(&buf[..count]));
+        self.items_written = end;
+
+        *self.items_filled += count;
+        *self.items_initialized = core::cmp::max(*self.items_initialized, *self.items_filled);
+
+        Ok(count)
+    }
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+impl<T> Buffer<T>
+where
+    T: GrowableBacking,
+{
+    /// Grow the underlying buffer by `additional` uninitialized items, increasing the capacity
+    /// without affecting the filled and initialized parts.
+    #[inline]
+    pub fn grow(&mut self, additional: usize) {
+        self.initializer.inner.grow(additional);
+        self.debug_assert_validity();
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> Buffer<Box<[MaybeUninit<T>]>> {
+    /// Allocate a new buffer of `capacity` uninitialized items, without zeroing them. This is named
+    /// differently from the vector-backed [`Buffer::with_capacity`], so that the backing need not
+    /// be spelled out when calling either of them.
+    pub fn with_boxed_capacity(capacity: usize) -> Self {
+        #[cfg(feature = "nightly")]
+        let boxed = Box::new_uninit_slice(capacity);
+
+        #[cfg(not(feature = "nightly"))]
+        let boxed = {
+            let mut vec = Vec::with_capacity(capacity);
+            // SAFETY: The items are MaybeUninit, and thus do not need to be initialized to be
+            // part of the length.
+            unsafe {
+                vec.set_len(capacity);
+            }
+            vec.into_boxed_slice()
+        };
+
+        Self::uninit(boxed)
+    }
+    /// Split the buffer into a boxed slice of the filled items, and a new buffer over the rest,
+    /// which keeps the items that were initialized but not filled as initialized. See
+    /// [`Buffer::<Vec<_>>::split_off_filled`](Buffer::split_off_filled).
+    pub fn split_off_filled(self) -> (AssertInit<Box<[MaybeUninit<T>]>>, Self) {
+        // SAFETY: Converting between boxed slices and vectors does not change the contents.
+        let buffer = unsafe { self.map_backing(Vec::from) };
+        let (filled, rest) = buffer.split_off_filled();
+
+        // SAFETY: Same as above.
+        unsafe {
+            (
+                AssertInit::new_unchecked(filled.into_inner().into_boxed_slice()),
+                rest.map_backing(Vec::into_boxed_slice),
+            )
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl Buffer<Vec<MaybeUninit<u8>>> {
+    /// Allocate a new vector-backed buffer of `capacity` zeroed bytes, which are marked as
+    /// initialized but not filled.
+    pub fn with_capacity_zeroed(capacity: usize) -> Self {
+        let mut buffer = Self::uninit(alloc::vec![MaybeUninit::new(0); capacity]);
+        // SAFETY: Every byte was initialized to zero above.
+        unsafe {
+            buffer.initializer.advance_to_end();
+        }
+        buffer
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> Buffer<Vec<MaybeUninit<T>>>
+where
+    T: Copy,
+{
+    /// Wrap the full capacity of `vec` in a buffer, so that its allocation can be reused. The
+    /// items within the length of the vector are marked as initialized (but not filled), so that
+    /// they need not be initialized again.
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut vec = core::mem::ManuallyDrop::new(vec);
+
+        let len = vec.len();
+        let capacity = vec.capacity();
+        let ptr = vec.as_mut_ptr();
+
+        // SAFETY: MaybeUninit<T> has the same layout as T, and since all items are wrapped in
+        // MaybeUninit, the whole capacity can be considered part of the length.
+        let vec = unsafe { Vec::from_raw_parts(ptr as *mut MaybeUninit<T>, capacity, capacity) };
+
+        let mut initializer = BufferInitializer::uninit(vec);
+        // SAFETY: The first `len` items were part of the original vector, and were hence
+        // initialized.
+        unsafe {
+            initializer.advance(len);
+        }
+        Self::from_initializer(initializer)
+    }
+    /// Turn the buffer back into a vector, with its length set to the number of items filled, and
+    /// its capacity kept.
+    pub fn into_vec(self) -> Vec<T> {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let mut vec = core::mem::ManuallyDrop::new(initializer.into_inner());
+
+        let capacity = vec.capacity();
+        let ptr = vec.as_mut_ptr();
+
+        // SAFETY: The first `items_filled` items are filled, and thus also initialized.
+        unsafe { Vec::from_raw_parts(ptr as *mut T, items_filled, capacity) }
+    }
+    /// Shrink the underlying vector to the initialized part, discarding the uninitialized part
+    /// and reallocating to reclaim memory. The filled and initialized parts, as well as their
+    /// cursors, are kept intact.
+    ///
+    /// After this, the [`capacity`](Self::capacity) of the buffer will be equal to the number of
+    /// items initialized.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        let items_initialized = self.initializer.items_initialized();
+        let vec = &mut self.initializer.inner;
+
+        vec.truncate(items_initialized);
+        vec.shrink_to_fit();
+
+        self.debug_assert_validity();
+    }
+    /// Turn the buffer into a boxed slice of exactly the filled items, shrinking the allocation
+    /// accordingly.
+    #[inline]
+    pub fn into_boxed_filled(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> Buffer<Vec<MaybeUninit<T>>> {
+    /// Split the buffer into a vector of the filled items, and a new buffer over the rest, e.g. to
+    /// take a completed frame as an owned value, while continuing to fill the rest. The new
+    /// buffer keeps the items that were initialized but not filled as initialized.
+    ///
+    /// Since the allocation cannot be split in two, the rest of the buffer is moved to a new
+    /// allocation, and the filled vector keeps the capacity of the original allocation.
+    pub fn split_off_filled(self) -> (AssertInit<Vec<MaybeUninit<T>>>, Self) {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let items_initialized = initializer.items_initialized();
+        let mut filled = initializer.into_inner();
+
+        let mut rest = Self::uninit(filled.split_off(items_filled));
+        // SAFETY: The items moved to the new vector are initialized within what was the
+        // initialized part, before being split off.
+        unsafe {
+            rest.initializer.advance(items_initialized - items_filled);
+        }
+
+        // SAFETY: What remains of the original vector is exactly the filled items.
+        (unsafe { AssertInit::new_unchecked(filled) }, rest)
+    }
+    /// Allocate a new vector-backed buffer of `capacity` uninitialized items, without zeroing
+    /// them.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Vec::with_capacity(capacity);
+        // SAFETY: The items are MaybeUninit, and thus do not need to be initialized to be part of
+        // the length.
+        unsafe {
+            vec.set_len(capacity);
+        }
+        Self::uninit(vec)
+    }
+    /// Turn the buffer into a vector of the filled items, keeping the capacity. Unlike
+    /// [`into_vec`](Self::into_vec), this works for items that are not `Copy`, and the items that
+    /// were initialized but not filled are dropped, rather than leaked.
+    pub fn into_filled_vec(self) -> Vec<T> {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let items_initialized = initializer.items_initialized();
+        let mut vec = core::mem::ManuallyDrop::new(initializer.into_inner());
+
+        let capacity = vec.capacity();
+        let ptr = vec.as_mut_ptr() as *mut T;
+
+        // SAFETY: The items after the filled part, but within the initialized part, are
+        // initialized, and since they are not part of the returned vector, they are not accessible
+        // after this.
+        unsafe {
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                ptr.add(items_filled),
+                items_initialized - items_filled,
+            ));
+        }
+
+        // SAFETY: The first `items_filled` items are filled, and thus also initialized.
+        unsafe { Vec::from_raw_parts(ptr, items_filled, capacity) }
+    }
+    /// Turn the buffer into an owned [`Cow`] of the filled items, without copying them. See
+    /// [`into_filled_vec`](Self::into_filled_vec).
+    #[inline]
+    pub fn into_filled_cow<'a>(self) -> Cow<'a, [T]>
+    where
+        T: Clone,
+    {
+        Cow::Owned(self.into_filled_vec())
+    }
+}
+impl<'a, Item> Buffer<AsUninit<&'a mut [Item]>> {
+    // TODO: Use a trait that makes the dynamic counter statically set to full.
+    /// Wrap an already initialized slice of any item type, marking it as fully initialized but
+    /// not filled.
+    #[inline]
+    pub fn from_slice_mut(slice: &'a mut [Item]) -> Self {
+        let mut initializer = BufferInitializer::new(slice);
+        unsafe {
+            initializer.advance_to_end();
+        }
+        Self::from_initializer(initializer)
+    }
+    /// Wrap an already initialized slice, where the first `filled` items are considered to be
+    /// filled, e.g. a scratch slice with meaningful data at the start, which is to be appended to.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `filled` is larger than the length of the slice.
+    #[inline]
+    pub fn from_slice_with_filled(slice: &'a mut [Item], filled: usize) -> Self {
+        assert!(
+            filled <= slice.len(),
+            "cannot mark {} items as filled in a slice of length {}",
+            filled,
+            slice.len()
+        );
+        let mut buffer = Self::from_slice_mut(slice);
+        buffer.items_filled = filled;
+        buffer
+    }
+    /// Turn the buffer into a [`Cow`] borrowing the filled part of the slice, for the full
+    /// lifetime of the slice.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn into_filled_cow(self) -> Cow<'a, [Item]>
+    where
+        Item: Clone,
+    {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let AsUninit(slice) = initializer.into_inner();
+
+        Cow::Borrowed(&slice[..items_filled])
+    }
+}
+impl<'a> Buffer<&'a mut [MaybeUninit<u8>]> {
+    #[inline]
+    pub fn from_uninit_slice_mut(slice: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self::uninit(slice)
+    }
+}
+impl<'a, T> Buffer<&'a mut [MaybeUninit<T>]> {
+    /// Turn the buffer into a [`Cow`] borrowing the filled part of the slice, for the full
+    /// lifetime of the slice.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn into_filled_cow(self) -> Cow<'a, [T]>
+    where
+        T: Clone,
+    {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let slice: &'a [MaybeUninit<T>] = initializer.into_inner();
+
+        // SAFETY: The first `items_filled` items are filled, and thus also initialized.
+        Cow::Borrowed(unsafe { crate::cast_uninit_to_init_slice(&slice[..items_filled]) })
+    }
+}
+#[cfg(feature = "heapless")]
+impl<'a, T, const N: usize> Buffer<crate::wrappers::HeaplessSpareCapacity<'a, T, N>> {
+    /// Wrap the spare capacity of `vec`, so that it can be filled without initializing it first.
+    #[inline]
+    pub fn from_heapless_spare_capacity(vec: &'a mut heapless::Vec<T, N>) -> Self {
+        Self::uninit(crate::wrappers::HeaplessSpareCapacity::new(vec))
+    }
+    /// Append the filled items to the vector whose spare capacity was filled, by increasing its
+    /// length. Returns the number of items appended.
+    pub fn commit_to_vec(self) -> usize {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let spare = initializer.into_inner();
+        let len = spare.vec.len();
+
+        // SAFETY: The items directly after the length of the vector are exactly the filled items,
+        // which are initialized.
+        unsafe {
+            spare.vec.set_len(len + items_filled);
+        }
+        items_filled
+    }
+}
+impl<T, const N: usize> Buffer<[MaybeUninit<T>; N]> {
+    /// Create a new uninitialized array-backed buffer, without having to spell out the array.
+    ///
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use uninit_tools::buffer::Buffer;
+    /// const SCRATCH: Buffer<[MaybeUninit<u8>; 512]> = Buffer::uninit_array();
+    ///
+    /// let mut buffer = SCRATCH;
+    /// assert_eq!(buffer.remaining(), 512);
+    ///
+    /// buffer.append(b"on the stack");
+    /// assert_eq!(buffer.filled_part(), b"on the stack");
+    /// ```
+    #[inline]
+    pub const fn uninit_array() -> Self {
+        // SAFETY: An array of MaybeUninit does not need to be initialized itself.
+        let array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        Self::uninit(array)
+    }
+    /// Wrap an already initialized array, marking it as both fully initialized and filled.
+    #[inline]
+    pub fn from_init_array(array: [T; N]) -> Self {
+        let mut initializer = BufferInitializer::uninit(array.map(MaybeUninit::new));
+        // SAFETY: Every item was wrapped from an initialized value of the source array.
+        unsafe {
+            initializer.advance_to_end();
+        }
+        let mut buffer = Self::from_initializer(initializer);
+        buffer.items_filled = N;
+        buffer
+    }
+    /// Collect the items of `iter` into a new array-backed buffer, filling it with at most `N`
+    /// items. Returns the buffer together with the number of items that did not fit, and were
+    /// hence dropped.
+    ///
+    /// Counting the items that did not fit consumes the rest of the iterator, which therefore must
+    /// be finite. Use [`from_iter_bounded_with_rest`](Self::from_iter_bounded_with_rest) to keep the
+    /// remaining items instead.
+    pub fn from_iter_bounded<I>(iter: I) -> (Self, usize)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let (buffer, rest) = Self::from_iter_bounded_with_rest(iter);
+        (buffer, rest.count())
+    }
+    /// Collect the items of `iter` into a new array-backed buffer, like
+    /// [`from_iter_bounded`](Self::from_iter_bounded), but return the iterator, from which no more
+    /// than `N` items have been taken, so that the items that did not fit can still be used. This
+    /// also makes it possible to pass infinite iterators.
+    pub fn from_iter_bounded_with_rest<I>(iter: I) -> (Self, I::IntoIter)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut array = [(); N].map(|()| MaybeUninit::uninit());
+        let mut count = 0;
+
+        for (slot, item) in array.iter_mut().zip(&mut iter) {
+            *slot = MaybeUninit::new(item);
+            count += 1;
+        }
+
+        let mut buffer = Self::uninit(array);
+        // SAFETY: The first `count` items of the array were initialized above.
+        unsafe {
+            buffer.assume_init(count);
+        }
+
+        (buffer, iter)
+    }
+}
+
+/// An iterator over the filled items of an array-backed [`Buffer`], yielding them by value.
+pub struct FilledIntoIter<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    alive: Range<usize>,
+}
+impl<T, const N: usize> FilledIntoIter<T, N> {
+    /// Get the items that have not yet been yielded.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The items within the alive range are filled, and have not been moved out.
+        unsafe { crate::cast_uninit_to_init_slice(&self.array[self.alive.clone()]) }
+    }
+}
+impl<T, const N: usize> Iterator for FilledIntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let index = self.alive.next()?;
+        // SAFETY: The item was within the alive range, and will thus never be read again.
+        Some(unsafe { self.array[index].as_ptr().read() })
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.alive.size_hint()
+    }
+}
+impl<T, const N: usize> DoubleEndedIterator for FilledIntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let index = self.alive.next_back()?;
+        // SAFETY: The item was within the alive range, and will thus never be read again.
+        Some(unsafe { self.array[index].as_ptr().read() })
+    }
+}
+impl<T, const N: usize> ExactSizeIterator for FilledIntoIter<T, N> {}
+impl<T, const N: usize> Drop for FilledIntoIter<T, N> {
+    fn drop(&mut self) {
+        let alive = self.alive.clone();
+        // SAFETY: The items within the alive range are initialized, and are never accessed again.
+        unsafe {
+            core::ptr::drop_in_place(crate::cast_uninit_to_init_slice_mut(&mut self.array[alive]));
+        }
+    }
+}
+impl<T, const N: usize> IntoIterator for Buffer<[MaybeUninit<T>; N]> {
+    type Item = T;
+    type IntoIter = FilledIntoIter<T, N>;
+
+    /// Turn the buffer into an iterator over its filled items. The items that were initialized
+    /// but not filled are dropped.
+    fn into_iter(self) -> Self::IntoIter {
+        let (initializer, items_filled) = self.into_raw_parts();
+        let items_initialized = initializer.items_initialized();
+        let mut array = initializer.into_inner();
+
+        // SAFETY: The items after the filled part, but within the initialized part, are
+        // initialized, and will not be accessible after this.
+        unsafe {
+            core::ptr::drop_in_place(crate::cast_uninit_to_init_slice_mut(
+                &mut array[items_filled..items_initialized],
+            ));
+        }
+
+        FilledIntoIter {
+            array,
+            alive: 0..items_filled,
+        }
+    }
+}
+impl<'a, T> IntoIterator for &'a Buffer<T>
+where
+    T: Initialize,
+{
+    type Item = &'a T::Item;
+    type IntoIter = core::slice::Iter<'a, T::Item>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_filled()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut Buffer<T>
+where
+    T: Initialize,
+{
+    type Item = &'a mut T::Item;
+    type IntoIter = core::slice::IterMut<'a, T::Item>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.filled_part_mut().iter_mut()
+    }
+}
+
+impl<'buffer, T> BufferRef<'buffer, T> {
+    #[inline]
+    pub fn items_filled(&self) -> usize {
+        self.inner.items_filled()
+    }
+
+    /// Reborrow the inner buffer, getting a buffer reference with a shorter lifetime.
+    #[inline]
+    pub fn by_ref(&mut self) -> BufferRef<'_, T> {
+        BufferRef { inner: self.inner }
+    }
+}
+
+impl<'buffer, T> BufferRef<'buffer, T>
+where
+    T: Initialize,
+{
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+    #[inline]
+    pub fn unfilled_parts(&mut self) -> (&mut [T::Item], &mut [MaybeUninit<T::Item>]) {
+        self.inner.unfilled_parts_mut()
+    }
+    /// Get a mutable and possibly-uninitialized reference to all of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not allow safe code to de-initialize the resulting slice.
+    #[inline]
+    pub unsafe fn unfilled_mut(&mut self) -> &mut [MaybeUninit<T::Item>] {
+        self.inner.unfilled_part_mut()
+    }
+    /// Advance the counter of the number of items filled.
+    ///
+    /// The number of items that are initialized is also updated accordingly, so that the number of
+    /// items initialized is always greater than or equal to the number of items filled.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the initialization invariant.
+    #[inline]
+    pub unsafe fn advance(&mut self, count: usize) {
+        self.inner.assume_init(count)
+    }
+    /// Advance the counter of the number of items filled, like [`advance`](Self::advance), but
+    /// return an error if that would go beyond the end of the buffer. The buffer is left untouched
+    /// on failure.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the initialization invariant.
+    #[inline]
+    pub unsafe fn try_advance(&mut self, count: usize) -> Result<(), AdvanceError> {
+        let available = self.remaining();
+
+        if count > available {
+            return Err(AdvanceError::BeyondCapacity { count, available });
+        }
+        self.inner.assume_init(count);
+
+        Ok(())
+    }
+    /// Advance the counter of the number of items filled, and the number of items initialized, to
+    /// the end of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the initialization invariant.
+    #[inline]
+    pub unsafe fn advance_all(&mut self) {
+        self.inner.assume_init_all();
+    }
+    /// Get the number of items that may be filled before the buffer is full. This is the same as
+    /// [`remaining`](Self::remaining), but named like in `bytes::BufMut`.
+    #[inline]
+    pub fn remaining_mut(&self) -> usize {
+        self.remaining()
+    }
+    /// Get the unfilled part of the buffer, which may be uninitialized. This is the same as
+    /// [`unfilled_mut`](Self::unfilled_mut), but named like in `bytes::BufMut`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not allow safe code to de-initialize the resulting slice.
+    #[inline]
+    pub unsafe fn chunk_mut(&mut self) -> &mut [MaybeUninit<T::Item>] {
+        self.unfilled_mut()
+    }
+    /// Advance the counter of the number of items filled. This is the same as
+    /// [`advance`](Self::advance), but named like in `bytes::BufMut`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the initialization invariant.
+    #[inline]
+    pub unsafe fn advance_mut(&mut self, count: usize) {
+        self.advance(count)
+    }
+    #[inline]
+    pub fn revert_to_start(&mut self) {
+        self.inner.revert_to_start()
+    }
+    #[inline]
+    pub fn fill_by_repeating(&mut self, item: T::Item)
+    where
+        T::Item: Copy,
+    {
+        self.inner.fill_by_repeating(item)
+    }
+    #[inline]
+    pub fn append(&mut self, slice: &[T::Item])
+    where
+        T::Item: Copy,
+    {
+        self.inner.append(slice)
+    }
+    /// See [`Buffer::append_exact`].
+    #[inline]
+    pub fn append_exact(&mut self, slice: &[T::Item]) -> Result<(), ExactError>
+    where
+        T::Item: Copy,
+    {
+        self.inner.append_exact(slice)
+    }
+    /// See [`Buffer::try_extend`].
+    #[inline]
+    pub fn try_extend<I>(&mut self, iter: I) -> bool
+    where
+        I: IntoIterator<Item = T::Item>,
+        T::Item: Copy,
+    {
+        self.inner.try_extend(iter)
+    }
+    /// See [`Buffer::fill_remaining_with`].
+    #[inline]
+    pub fn fill_remaining_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T::Item,
+    {
+        self.inner.fill_remaining_with(f)
+    }
+}
+impl<T> BufferRef<'_, T>
+where
+    T: Initialize<Item = u8>,
+{
+    #[inline]
+    pub fn fill_by_zeroing(&mut self) {
+        self.inner.fill_by_zeroing()
+    }
+}
+
+#[cfg(feature = "bytes")]
+unsafe impl<T> bytes::BufMut for BufferRef<'_, T>
+where
+    T: Initialize<Item = u8>,
+{
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.remaining()
+    }
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.remaining(),
+            "cannot advance by {} bytes, when only {} bytes are remaining",
+            cnt,
+            self.remaining()
+        );
+        self.advance(cnt)
+    }
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        // SAFETY: UninitSlice never allows uninitialized bytes to be written, and thus the
+        // unfilled part cannot be de-initialized.
+        bytes::buf::UninitSlice::uninit(unsafe { self.unfilled_mut() })
+    }
+}
+
+/// A guard that checks the invariants of a buffer when dropped, obtained from
+/// [`Buffer::guarded`].
+pub struct BufferGuard<'buffer, T>
+where
+    T: Initialize,
+{
+    inner: &'buffer mut Buffer<T>,
+}
+impl<T> Deref for BufferGuard<'_, T>
+where
+    T: Initialize,
+{
+    type Target = Buffer<T>;
+
+    #[inline]
+    fn deref(&self) -> &Buffer<T> {
+        self.inner
+    }
+}
+impl<T> DerefMut for BufferGuard<'_, T>
+where
+    T: Initialize,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Buffer<T> {
+        self.inner
+    }
+}
+impl<T> Drop for BufferGuard<'_, T>
+where
+    T: Initialize,
+{
+    fn drop(&mut self) {
+        #[cfg(feature = "strict")]
+        self.inner.assert_validity();
+
+        #[cfg(not(feature = "strict"))]
+        self.inner.debug_assert_validity();
+    }
+}
+
+/// A cursor to the unfilled part of a buffer, obtained from [`Buffer::fill_cursor`]. It
+/// dereferences to the unfilled part, and when dropped, advances the buffer by the number of
+/// items recorded as initialized.
+pub struct FillCursor<'buffer, T>
+where
+    T: Initialize,
+{
+    inner: &'buffer mut Buffer<T>,
+    recorded: usize,
+}
+impl<T> FillCursor<'_, T>
+where
+    T: Initialize,
+{
+    /// Get the number of items that have been recorded as initialized.
+    #[inline]
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+    /// Record that the first `count` items of the cursor have been initialized, replacing any
+    /// previously recorded count. The buffer is advanced by this count once the cursor is
+    /// dropped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the first `count` items of the slice that the cursor
+    /// dereferences to have been initialized, and that they remain initialized until the cursor
+    /// is dropped.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `count` is larger than the number of unfilled items.
+    #[inline]
+    pub unsafe fn record(&mut self, count: usize) {
+        assert!(
+            count <= self.inner.remaining(),
+            "cannot record {} items when only {} items are unfilled",
+            count,
+            self.inner.remaining()
+        );
+        self.recorded = count;
+    }
+    /// Record that the first `count` items have been initialized, and advance the buffer
+    /// accordingly.
+    ///
+    /// # Safety
+    ///
+    /// The same as for [`record`](Self::record).
+    ///
+    /// # Panics
+    ///
+    /// The same as for [`record`](Self::record).
+    #[inline]
+    pub unsafe fn commit(mut self, count: usize) {
+        self.record(count);
+    }
+}
+impl<T> Deref for FillCursor<'_, T>
+where
+    T: Initialize,
+{
+    type Target = [MaybeUninit<T::Item>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.unfilled_part()
+    }
+}
+impl<T> DerefMut for FillCursor<'_, T>
+where
+    T: Initialize,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.forget_init_past(self.inner.items_filled);
+
+        // SAFETY: The initialized-but-unfilled part was forgotten above, and thus nothing that is
+        // considered initialized can be de-initialized.
+        unsafe { self.inner.unfilled_part_mut() }
+    }
+}
+impl<T> Drop for FillCursor<'_, T>
+where
+    T: Initialize,
+{
+    fn drop(&mut self) {
+        // SAFETY: The caller of `record` has ensured that the recorded items are initialized.
+        unsafe { self.inner.assume_init(self.recorded) }
+    }
+}
+
+/// The width of the big-endian length prefix of a frame, used by
+/// [`Buffer::verify_and_split_frame`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PrefixWidth {
+    U8,
+    U16,
+    U32,
+    U64,
+}
+impl PrefixWidth {
+    /// Get the number of bytes of the prefix.
+    #[inline]
+    pub const fn byte_len(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 4,
+            Self::U64 => 8,
+        }
+    }
+}
+
+/// The error returned when advancing the filledness cursor of a buffer fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AdvanceError {
+    /// Advancing by `count` items would go beyond the initialized part, which only has `available`
+    /// items left that are initialized but not filled.
+    BeyondInitialized { count: usize, available: usize },
+    /// Advancing by `count` items would go beyond the end of the buffer, which only has `available`
+    /// unfilled items left.
+    BeyondCapacity { count: usize, available: usize },
+}
+impl fmt::Display for AdvanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BeyondInitialized { count, available } => write!(
+                f,
+                "cannot advance by {} items beyond the initialized region ({} available)",
+                count, available
+            ),
+            Self::BeyondCapacity { count, available } => write!(
+                f,
+                "cannot advance by {} items beyond the end of the buffer ({} available)",
+                count, available
+            ),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for AdvanceError {}
+
+/// The error returned when an exact number of items does not fit in a buffer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ExactError {
+    /// The number of items that were requested to fit.
+    pub needed: usize,
+    /// The number of unfilled items that were actually available.
+    pub available: usize,
+}
+impl fmt::Display for ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot fit {} items in a buffer with only {} items remaining",
+            self.needed, self.available
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ExactError {}
+
+impl<T> fmt::Debug for Buffer<T>
+where
+    T: Initialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ptr = self.initializer().all_uninit().as_ptr();
+        let items_init = self.initializer().items_initialized();
+        let items_filled = self.items_filled();
+        let total = self.capacity();
+
+        if f.alternate() {
+            // NOTE: An empty buffer is both fully filled and fully initialized, and should not
+            // be printed as NaN.
+            let percentage = |count: usize| {
+                if total == 0 {
+                    100.0
+                } else {
+                    count as f64 / total as f64 * 100.0
+                }
+            };
+            let init_percentage = percentage(items_init);
+            let filled_percentage = percentage(items_filled);
+            write!(
+                f,
+                "[buffer at {:?}, {} filled ({:.1}%), {} init ({:.1}%), {} total]",
+                ptr, items_filled, filled_percentage, items_init, init_percentage, total
+            )
+        } else {
+            write!(
+                f,
+                "[buffer at {:?}, {}/{}/{}]",
+                ptr, items_filled, items_init, total
+            )
+        }
+    }
+}
+impl<T> fmt::Debug for BufferRef<'_, T>
+where
+    T: Initialize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+impl<T> PartialEq for Buffer<T>
+where
+    T: Initialize,
+    T::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.filled_part() == other.filled_part()
+    }
+}
+impl<T> Eq for Buffer<T>
+where
+    T: Initialize,
+    T::Item: Eq,
+{
+}
+impl<T> PartialEq<[T::Item]> for Buffer<T>
+where
+    T: Initialize,
+    T::Item: PartialEq,
+{
+    fn eq(&self, other: &[T::Item]) -> bool {
+        self.filled_part() == other
+    }
+}
+impl<'a, T> PartialEq<&'a [T::Item]> for Buffer<T>
+where
+    T: Initialize,
+    T::Item: PartialEq,
+{
+    fn eq(&self, other: &&'a [T::Item]) -> bool {
+        self.filled_part() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_buffer_ops() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
+        assert_eq!(buffer.capacity(), 32);
+        assert_eq!(buffer.remaining(), 32);
+        assert!(buffer.is_empty());
+        assert!(!buffer.is_full());
+        assert!(buffer.initializer().is_completely_uninit());
+        assert!(!buffer.initializer().is_completely_init());
+        assert_eq!(buffer.initializer().items_initialized(), 0);
+        assert_eq!(buffer.initializer().remaining(), 32);
+        assert_eq!(buffer.filled_part(), &[]);
+        assert_eq!(buffer.unfilled_part().len(), 32);
         assert_eq!(buffer.filled_part_mut(), &mut []);
         assert_eq!(unsafe { buffer.unfilled_part_mut().len() }, 32);
         // TODO: more
 
-        let BufferParts {
-            filled_part,
-            unfilled_init_part,
-            unfilled_uninit_part,
-        } = buffer.all_parts();
-        assert_eq!(filled_part, &[]);
-        assert_eq!(unfilled_init_part, &[]);
-        assert_eq!(unfilled_uninit_part.len(), 32);
+        let BufferParts {
+            filled_part,
+            unfilled_init_part,
+            unfilled_uninit_part,
+        } = buffer.all_parts();
+        assert_eq!(filled_part, &[]);
+        assert_eq!(unfilled_init_part, &[]);
+        assert_eq!(unfilled_uninit_part.len(), 32);
+
+        let BufferPartsMut {
+            filled_part,
+            unfilled_init_part,
+            unfilled_uninit_part,
+        } = buffer.all_parts_mut();
+        assert_eq!(filled_part, &mut []);
+        assert_eq!(unfilled_init_part, &mut []);
+        assert_eq!(unfilled_uninit_part.len(), 32);
+
+        let src = b"I am a really nice slice!";
+        let modified = b"I am a really wise slice!";
+
+        buffer.append(src);
+
+        assert!(!buffer.is_empty());
+        assert!(!buffer.is_full());
+        assert_eq!(buffer.filled_part(), src);
+        assert_eq!(buffer.filled_part_mut(), src);
+        assert!(!buffer.initializer().is_completely_init());
+        assert!(!buffer.initializer().is_completely_uninit());
+        assert_eq!(buffer.initializer().init_part(), src);
+        assert_eq!(buffer.initializer_mut().init_part_mut(), src);
+        assert_eq!(buffer.items_filled(), src.len());
+        assert_eq!(buffer.remaining(), 32 - src.len());
+
+        {
+            let slice_mut = buffer.filled_part_mut();
+            slice_mut[14] = b'w';
+            slice_mut[16] = b's';
+        }
+
+        assert!(!buffer.is_empty());
+        assert!(!buffer.is_full());
+        assert_eq!(buffer.filled_part(), modified);
+        assert_eq!(buffer.filled_part_mut(), modified);
+        assert_eq!(buffer.initializer().init_part(), modified);
+        assert_eq!(buffer.initializer_mut().init_part_mut(), modified);
+        assert_eq!(buffer.items_filled(), src.len());
+        assert_eq!(buffer.remaining(), 32 - src.len());
+        assert_eq!(buffer.unfilled_part().len(), 32 - src.len());
+        assert_eq!(unsafe { buffer.unfilled_part_mut().len() }, 32 - src.len());
+
+        let mut initializer = buffer.into_initializer();
+
+        assert_eq!(initializer.items_initialized(), modified.len());
+        assert_eq!(initializer.remaining(), 7);
+        initializer.partially_fill_uninit_part(3_usize, 0xFF_u8);
+        initializer.partially_zero_uninit_part(1_usize);
+        assert_eq!(initializer.remaining(), 3);
+
+        let modified_and_garbage_items = b"I am a really wise slice!\xFF\xFF\xFF\x00";
+        let (init, uninit) = initializer.init_uninit_parts();
+        assert_eq!(init, modified_and_garbage_items);
+        assert_eq!(uninit.len(), 3);
+
+        let (init, uninit) = initializer.init_uninit_parts_mut();
+        assert_eq!(init, modified_and_garbage_items);
+        init[2] = b'e';
+        assert_eq!(uninit.len(), 3);
+
+        let mut buffer = Buffer::from_initializer(initializer);
+        buffer.advance(modified.len());
+
+        let BufferParts {
+            filled_part,
+            unfilled_init_part,
+            unfilled_uninit_part,
+        } = buffer.all_parts();
+        let modified_again = b"I em a really wise slice!";
+        assert_eq!(filled_part, modified_again);
+        assert_eq!(unfilled_init_part, b"\xFF\xFF\xFF\x00");
+        assert_eq!(unfilled_uninit_part.len(), 3);
+
+        let BufferPartsMut {
+            filled_part,
+            unfilled_init_part,
+            unfilled_uninit_part,
+        } = buffer.all_parts_mut();
+        assert_eq!(filled_part, modified_again);
+        assert_eq!(unfilled_init_part, b"\xFF\xFF\xFF\x00");
+        unfilled_init_part[2] = b'\x13';
+        unfilled_init_part[3] = b'\x37';
+        assert_eq!(unfilled_init_part, b"\xFF\xFF\x13\x37");
+        assert_eq!(unfilled_uninit_part.len(), 3);
+
+        let rest = b" Right?";
+        buffer.append(rest);
+
+        assert_eq!(buffer.items_filled(), 32);
+        assert!(!buffer.is_empty());
+        assert!(buffer.is_full());
+        assert_eq!(buffer.remaining(), 0);
+
+        let total = b"I em a really wise slice! Right?";
+        assert_eq!(buffer.filled_part(), total);
+        assert_eq!(buffer.filled_part_mut(), total);
+        assert_eq!(buffer.initializer().remaining(), 0);
+        assert_eq!(buffer.initializer().items_initialized(), 32);
+        assert!(buffer.initializer().is_completely_init());
+        assert!(!buffer.initializer().is_completely_uninit());
+
+        buffer.advance_to_init_part();
+
+        // TODO: Shorthand?
+        let initialized: [u8; 32] = buffer.into_initializer().try_into_init().unwrap().into();
+        assert_eq!(&initialized, total);
+    }
+    #[test]
+    fn debug_impl() {
+        let array = [MaybeUninit::<u8>::uninit(); 32];
+        let mut buffer = Buffer::uninit(array);
+        buffer.append(b"Hello, world!");
+        buffer.initializer_mut().partially_zero_uninit_part(13);
+
+        assert_eq!(
+            format!("{:?}", buffer),
+            format!(
+                "[buffer at {:p}, 13/26/32]",
+                buffer.initializer().all_uninit().as_ptr()
+            )
+        );
+        assert_eq!(
+            format!("{:#?}", buffer),
+            format!(
+                "[buffer at {:p}, 13 filled (40.6%), 26 init (81.2%), 32 total]",
+                buffer.initializer().all_uninit().as_ptr()
+            )
+        );
+
+        let array: [MaybeUninit<u8>; 0] = [];
+        let empty = Buffer::uninit(array);
+        assert_eq!(
+            format!("{:#?}", empty),
+            format!(
+                "[buffer at {:p}, 0 filled (100.0%), 0 init (100.0%), 0 total]",
+                empty.initializer().all_uninit().as_ptr()
+            )
+        );
+    }
+    #[test]
+    fn debug_impl_by_ref() {
+        let array = [MaybeUninit::<u8>::uninit(); 32];
+        let mut buffer = Buffer::uninit(array);
+        buffer.append(b"Hello, world!");
+        buffer.initializer_mut().partially_zero_uninit_part(13);
+        let ptr = buffer.initializer().all_uninit().as_ptr();
+
+        assert_eq!(
+            format!("{:?}", buffer.by_ref()),
+            format!("[buffer at {:p}, 13/26/32]", ptr)
+        );
+        assert_eq!(
+            format!("{:#?}", buffer.by_ref()),
+            format!(
+                "[buffer at {:p}, 13 filled (40.6%), 26 init (81.2%), 32 total]",
+                ptr
+            )
+        );
+    }
+    #[test]
+    fn fill_ramp() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 300]);
+        buffer.append(b"abc");
+        buffer.fill_ramp(250, 3);
+
+        assert!(buffer.is_full());
+        assert!(buffer.initializer().is_completely_init());
+
+        let filled = buffer.filled_part();
+        assert_eq!(&filled[..7], b"abc\xFA\xFD\x00\x03");
+        // 297 items of the ramp: 250 + 296 * 3 = 1138 = 4 * 256 + 114.
+        assert_eq!(&filled[297..], &[108, 111, 114]);
+    }
+    #[test]
+    fn compact() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+
+        // No-op when nothing is filled.
+        buffer.compact();
+        assert_eq!(buffer.items_filled(), 0);
+        assert_eq!(buffer.initializer().items_initialized(), 0);
+
+        buffer.append(b"Hello, ");
+        buffer.initializer_mut().partially_fill_uninit_part(3, b'!');
+        assert_eq!(buffer.unfilled_init_part(), b"!!!");
+
+        buffer.compact();
+        assert_eq!(buffer.items_filled(), 0);
+        assert_eq!(buffer.initializer().items_initialized(), 3);
+        assert_eq!(buffer.initializer().init_part(), b"!!!");
+
+        buffer.advance(1);
+        buffer.append(b" Hello again!");
+        assert_eq!(buffer.filled_part(), b"! Hello again!");
+        assert_eq!(buffer.remaining(), 2);
+    }
+    #[test]
+    fn try_advance() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.initializer_mut().partially_zero_uninit_part(8);
+
+        assert_eq!(buffer.try_advance(3), Ok(()));
+        assert_eq!(buffer.items_filled(), 3);
+
+        assert_eq!(
+            buffer.try_advance(6),
+            Err(AdvanceError::BeyondInitialized {
+                count: 6,
+                available: 5
+            })
+        );
+        assert_eq!(buffer.items_filled(), 3);
+
+        assert_eq!(buffer.try_advance(5), Ok(()));
+        assert_eq!(buffer.items_filled(), 8);
+        assert_eq!(buffer.unfilled_init_part(), &[]);
+    }
+    #[test]
+    fn buffer_ref_filling() {
+        fn fill<T: Initialize<Item = u8>>(mut buffer_ref: BufferRef<'_, T>) {
+            assert_eq!(buffer_ref.append_exact(b"head"), Ok(()));
+            assert_eq!(
+                buffer_ref.append_exact(&[0; 16]),
+                Err(ExactError {
+                    needed: 16,
+                    available: 12
+                })
+            );
+            assert!(buffer_ref.try_extend(b" mid".iter().copied()));
+
+            let mut next = b'a';
+            buffer_ref.fill_remaining_with(|| {
+                next += 1;
+                next - 1
+            });
+        }
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        fill(buffer.by_ref());
+        assert_eq!(buffer.filled_part(), b"head midabcdefgh");
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut buffer_ref = buffer.by_ref();
+        assert!(!buffer_ref.try_extend(0..=16));
+        assert!(buffer.is_full());
+    }
+    #[test]
+    fn buffer_ref_try_advance() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut buffer_ref = buffer.by_ref();
+
+        unsafe {
+            crate::fill_uninit_slice(&mut buffer_ref.unfilled_mut()[..4], 0xFF_u8);
+            assert_eq!(buffer_ref.try_advance(4), Ok(()));
+
+            assert_eq!(
+                buffer_ref.try_advance(13),
+                Err(AdvanceError::BeyondCapacity {
+                    count: 13,
+                    available: 12
+                })
+            );
+            assert_eq!(buffer_ref.items_filled(), 4);
+
+            crate::fill_uninit_slice(buffer_ref.unfilled_mut(), 0x00_u8);
+            assert_eq!(buffer_ref.try_advance(12), Ok(()));
+        }
+        assert!(buffer.is_full());
+        assert!(buffer.initializer().is_completely_init());
+    }
+    #[test]
+    fn append_if_fits() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+
+        assert!(buffer.append_if_fits(b"Hello"));
+        assert_eq!(buffer.filled_part(), b"Hello");
+
+        assert!(!buffer.append_if_fits(b", world!"));
+        assert_eq!(buffer.filled_part(), b"Hello");
+        assert_eq!(buffer.initializer().items_initialized(), 5);
+
+        assert!(buffer.append_if_fits(b"!!!"));
+        assert!(buffer.is_full());
+        assert!(buffer.append_if_fits(b""));
+        assert!(!buffer.append_if_fits(b"?"));
+    }
+    #[test]
+    fn append_exact() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+
+        assert_eq!(buffer.append_exact(b"Hello"), Ok(()));
+        assert_eq!(
+            buffer.append_exact(b", world!"),
+            Err(ExactError {
+                needed: 8,
+                available: 3
+            })
+        );
+        assert_eq!(buffer.filled_part(), b"Hello");
+        assert_eq!(buffer.initializer().items_initialized(), 5);
+
+        assert_eq!(buffer.append_exact(b"!!!"), Ok(()));
+        assert!(buffer.is_full());
+    }
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn filled_as() {
+        #[repr(C, align(8))]
+        struct Aligned([MaybeUninit<u8>; 16]);
+
+        let mut storage = Aligned([MaybeUninit::uninit(); 16]);
+        let mut buffer = Buffer::uninit(&mut storage.0[..]);
+
+        buffer.append(&1_u32.to_ne_bytes());
+        buffer.append(&2_u32.to_ne_bytes());
+        assert_eq!(buffer.filled_as::<u32>(), Some(&[1_u32, 2][..]));
+
+        buffer.append(&[0; 2]);
+        assert_eq!(buffer.filled_as::<u32>(), None);
+
+        let mut buffer = Buffer::uninit(&mut storage.0[1..]);
+        buffer.append(&[0; 8]);
+        assert_eq!(buffer.filled_as::<u32>(), None);
+    }
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn filled_as_mut() {
+        #[repr(C, align(8))]
+        struct Aligned([u8; 8]);
+
+        let mut storage = Aligned(*b"\x00\x01\x00\x02\x00\x03\x00\x04");
+        let mut buffer = Buffer::new(&mut storage.0[..]);
+
+        buffer.advance(7);
+        assert_eq!(buffer.filled_as_mut::<u16>(), None);
+
+        buffer.advance(1);
+        for value in buffer.filled_as_mut::<u16>().unwrap() {
+            *value = value.swap_bytes();
+        }
+        assert_eq!(buffer.filled_part(), b"\x01\x00\x02\x00\x03\x00\x04\x00");
+    }
+    #[test]
+    fn append_into_init_tail() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"Hi");
+        buffer.initializer_mut().partially_zero_uninit_part(8);
+
+        buffer.append_into_init_tail(|tail| {
+            assert_eq!(tail, &[0_u8; 8]);
+            tail[..5].copy_from_slice(b", you");
+            5
+        });
+        assert_eq!(buffer.filled_part(), b"Hi, you");
+        assert_eq!(buffer.unfilled_init_part(), &[0_u8; 3]);
+        assert_eq!(buffer.initializer().items_initialized(), 10);
+
+        buffer.append_into_init_tail(|tail| tail.len());
+        assert_eq!(buffer.filled_part(), b"Hi, you\0\0\0");
+        assert_eq!(buffer.unfilled_init_part(), &[]);
+    }
+    #[test]
+    #[should_panic]
+    fn append_into_init_tail_out_of_bounds() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.initializer_mut().partially_zero_uninit_part(4);
+
+        buffer.append_into_init_tail(|tail| tail.len() + 1);
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_from_bounded() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut reader: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(buffer.read_from_bounded(&mut reader, 10).unwrap(), 10);
+        assert_eq!(buffer.filled_part(), b"The quick ");
+        // Only what was needed for the read, should have been initialized.
+        assert_eq!(buffer.initializer().items_initialized(), 10);
+
+        assert_eq!(buffer.read_from_bounded(&mut reader, 10).unwrap(), 0);
+
+        assert_eq!(buffer.read_from_bounded(&mut reader, 64).unwrap(), 6);
+        assert_eq!(buffer.filled_part(), b"The quick brown ");
+        assert!(buffer.is_full());
+        assert_eq!(reader, b"fox jumps over the lazy dog");
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn overwrite_from() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+
+        let mut first: &[u8] = b"first reader";
+        assert_eq!(buffer.overwrite_from(&mut first).unwrap(), 8);
+        assert_eq!(buffer.filled_part(), b"first re");
+        assert_eq!(first, b"ader");
+
+        let mut second: &[u8] = b"second";
+        assert_eq!(buffer.overwrite_from(&mut second).unwrap(), 6);
+        assert_eq!(buffer.filled_part(), b"second");
+        // The tail from the first read is still initialized.
+        assert_eq!(buffer.initializer().items_initialized(), 8);
+        assert_eq!(buffer.unfilled_init_part(), b"re");
+    }
+    #[test]
+    fn from_slice_with_filled() {
+        let mut storage = *b"head????????";
+        let mut buffer = Buffer::from_slice_with_filled(&mut storage[..], 4);
+
+        assert_eq!(buffer.filled_part(), b"head");
+        assert_eq!(buffer.remaining(), 8);
+        assert_eq!(buffer.unfilled_init_part().len(), 8);
+
+        buffer.append(b" to tail");
+        assert_eq!(buffer.filled_part(), b"head to tail");
+        assert!(buffer.is_full());
+    }
+    #[test]
+    #[should_panic]
+    fn from_slice_with_filled_too_long() {
+        let mut storage = [0_u8; 4];
+        let _ = Buffer::from_slice_with_filled(&mut storage[..], 5);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn with_boxed_capacity() {
+        let mut buffer = Buffer::with_boxed_capacity(4096);
+        assert_eq!(buffer.capacity(), 4096);
+        assert_eq!(buffer.initializer().items_initialized(), 0);
+
+        buffer.append(b"allocated");
+        assert_eq!(buffer.filled_part(), b"allocated");
+        assert_eq!(buffer.remaining(), 4096 - 9);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_with_capacity() {
+        let buffer = Buffer::<Vec<MaybeUninit<u8>>>::with_capacity(64);
+        assert_eq!(buffer.capacity(), 64);
+        assert_eq!(buffer.remaining(), 64);
+        assert_eq!(buffer.initializer().items_initialized(), 0);
+
+        let mut buffer = Buffer::with_capacity_zeroed(64);
+        assert_eq!(buffer.capacity(), 64);
+        assert_eq!(buffer.remaining(), 64);
+        assert_eq!(buffer.initializer().items_initialized(), 64);
+        assert_eq!(buffer.unfilled_init_part(), &[0; 64][..]);
+
+        buffer.append(b"zeroed");
+        assert_eq!(buffer.remaining(), 58);
+    }
+    #[cfg(all(feature = "libc", unix))]
+    #[test]
+    fn as_iovec_mut() {
+        let mut fds = [0; 2];
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        let [read_fd, write_fd] = fds;
+
+        let message = b"syscall";
+        let written = unsafe { libc::write(write_fd, message.as_ptr().cast(), message.len()) };
+        assert_eq!(written, message.len() as isize);
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"pre-");
+
+        let iovec = buffer.as_iovec_mut();
+        assert_eq!(iovec.iov_len, 12);
+
+        let count = unsafe { libc::readv(read_fd, &iovec, 1) };
+        assert_eq!(count, message.len() as isize);
+        unsafe { buffer.assume_init(count as usize) };
+
+        assert_eq!(buffer.filled_part(), b"pre-syscall");
+
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+    }
+    #[test]
+    fn first_uninit_and_unfilled_offsets() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        assert_eq!(buffer.first_uninit_offset(), Some(0));
+        assert_eq!(buffer.first_unfilled_offset(), Some(0));
+
+        buffer.append(b"abc");
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+        assert_eq!(buffer.first_uninit_offset(), Some(5));
+        assert_eq!(buffer.first_unfilled_offset(), Some(3));
+
+        buffer.initializer_mut().zero_uninit_part();
+        assert_eq!(buffer.first_uninit_offset(), None);
+        assert_eq!(buffer.first_unfilled_offset(), Some(3));
+
+        buffer.advance(5);
+        assert_eq!(buffer.first_uninit_offset(), None);
+        assert_eq!(buffer.first_unfilled_offset(), None);
+    }
+    #[test]
+    fn append_joined() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 17]);
+
+        let fields: [&[u8]; 3] = [b"id", b"name", b"age"];
+        assert_eq!(buffer.append_joined(fields.iter().copied(), b","), Ok(()));
+        assert_eq!(buffer.filled_part(), b"id,name,age");
+
+        let fields: [&[u8]; 3] = [b"1", b"ab", b"3"];
+        assert_eq!(
+            buffer.append_joined(fields.iter().copied(), b", "),
+            Err(ExactError {
+                needed: 8,
+                available: 6
+            })
+        );
+        assert_eq!(buffer.filled_part(), b"id,name,age");
+
+        assert_eq!(buffer.append_joined(fields.iter().copied(), b";"), Ok(()));
+        assert_eq!(buffer.filled_part(), b"id,name,age1;ab;3");
+        assert!(buffer.is_full());
+
+        assert_eq!(buffer.append_joined(core::iter::empty(), b","), Ok(()));
+
+        // Single-pass iterators can be used as well.
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let line = b"a b c d e";
+        let mut words = line.split(|&byte| byte == b' ');
+        assert_eq!(
+            buffer.append_joined(&mut words, b", "),
+            Err(ExactError {
+                needed: 13,
+                available: 8
+            })
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.initializer().items_initialized(), 7);
+
+        assert_eq!(
+            buffer.append_joined(&mut line[..5].split(|&byte| byte == b' '), b"; "),
+            Ok(())
+        );
+        assert_eq!(buffer.filled_part(), b"a; b; c");
+    }
+    #[test]
+    fn append_joined_overflowing_length() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<()>::uninit(); 4]);
+
+        let separator = [(); usize::MAX];
+        let pieces: [&[()]; 3] = [&[()], &[()], &[()]];
+        assert_eq!(
+            buffer.append_joined(pieces.iter().copied(), &separator),
+            Err(ExactError {
+                needed: usize::MAX,
+                available: 4
+            })
+        );
+        assert!(buffer.is_empty());
+
+        let pieces: [&[()]; 2] = [&separator, &[()]];
+        assert_eq!(
+            buffer.append_joined(pieces.iter().copied(), &[]),
+            Err(ExactError {
+                needed: usize::MAX,
+                available: 4
+            })
+        );
+        assert!(buffer.is_empty());
+    }
+    #[test]
+    fn from_init_array() {
+        let buffer = Buffer::from_init_array([1_u8; 8]);
+        assert_eq!(buffer.filled_part(), &[1_u8; 8]);
+        assert!(buffer.is_full());
+
+        let initializer = Buffer::from_init_array([1_u32, 2, 3, 4]).into_initializer();
+        let array: [u32; 4] = initializer.try_into_init().ok().unwrap().into();
+        assert_eq!(array, [1, 2, 3, 4]);
+    }
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn fill_by_random() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 64]);
+        buffer.append(b"nonce:");
+
+        buffer.fill_by_random().unwrap();
+        assert!(buffer.is_full());
+        assert_eq!(&buffer.filled_part()[..6], b"nonce:");
+        // The odds of 58 random bytes all being zero are negligible.
+        assert!(buffer.filled_part()[6..].iter().any(|&byte| byte != 0));
+    }
+    #[test]
+    fn append_returning_offset() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+
+        let records: [&[u8]; 4] = [b"alpha", b"beta", b"gamma", b"delta"];
+        let offsets = records.map(|record| buffer.append_returning_offset(record));
+
+        assert_eq!(offsets, [Ok(0), Ok(5), Ok(9), Err(3)]);
+        assert_eq!(buffer.filled_part(), b"alphabetagamma");
+    }
+    #[cfg(feature = "base64")]
+    #[test]
+    fn base64_round_trip() {
+        let mut encoded = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        encoded.append_base64(b"uninit").unwrap();
+        assert_eq!(encoded.filled_part(), b"dW5pbml0");
+
+        assert_eq!(
+            encoded.append_base64(b"tools!!"),
+            Err(ExactError {
+                needed: 12,
+                available: 8
+            })
+        );
+        assert_eq!(encoded.filled_part(), b"dW5pbml0");
+
+        encoded.decode_base64_in_place().unwrap();
+        assert_eq!(encoded.readable(), b"uninit");
+        assert_eq!(encoded.filled_part(), b"dW5pbml0uninit");
+
+        encoded.consume(6);
+        encoded.append_base64(b"ok").unwrap();
+        encoded.decode_base64_in_place().unwrap();
+        assert_eq!(encoded.readable(), b"ok");
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(b"dW5pbml0");
+        assert_eq!(
+            buffer.decode_base64_in_place(),
+            Err(base64::DecodeSliceError::OutputSliceTooSmall)
+        );
+        assert_eq!(buffer.readable(), b"dW5pbml0");
+        assert_eq!(buffer.initializer().items_initialized(), 8);
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"dW5p!ml0");
+        assert!(buffer.decode_base64_in_place().is_err());
+        assert_eq!(buffer.readable(), b"dW5p!ml0");
+
+        buffer.append(b"=");
+        assert!(buffer.decode_base64_in_place().is_err());
+        assert_eq!(buffer.readable(), b"dW5p!ml0=");
+    }
+    #[test]
+    fn as_str() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        assert_eq!(buffer.as_str(), Ok(""));
+
+        buffer.append("grüße".as_bytes());
+        assert_eq!(buffer.as_str(), Ok("grüße"));
+        assert_eq!(unsafe { buffer.as_str_unchecked() }, "grüße");
+
+        buffer.append(&[0xC3]);
+        let error = buffer.as_str().unwrap_err();
+        assert_eq!(error.valid_up_to(), 7);
+    }
+    #[test]
+    fn append_maybe_uninit() {
+        let mut src = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        src.append(b"forward");
+
+        let mut dst = Buffer::uninit([MaybeUninit::<u8>::uninit(); 12]);
+        dst.append(b"fast ");
+
+        // SAFETY: Only the filled prefix of the source is forwarded, which is initialized.
+        unsafe {
+            let filled = src.items_filled();
+            dst.append_maybe_uninit(&src.initializer().all_uninit()[..filled]);
+        }
+        assert_eq!(dst.filled_part(), b"fast forward");
+        assert!(dst.is_full());
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_from_yielding() {
+        struct Intermittent<'a> {
+            chunks: &'a [&'a [u8]],
+            blocked: bool,
+        }
+        impl std::io::Read for Intermittent<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.blocked = !self.blocked;
+                if self.blocked {
+                    return Err(std::io::ErrorKind::WouldBlock.into());
+                }
+                let (chunk, rest) = match self.chunks.split_first() {
+                    Some(split) => split,
+                    None => return Ok(0),
+                };
+                let count = core::cmp::min(chunk.len(), buf.len());
+                buf[..count].copy_from_slice(&chunk[..count]);
+                self.chunks = rest;
+                Ok(count)
+            }
+        }
+
+        let mut reader = Intermittent {
+            chunks: &[b"cooperative ", b"yield"],
+            blocked: false,
+        };
+        let mut idle_calls = 0;
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
+        let count = buffer
+            .read_from_yielding(&mut reader, || idle_calls += 1)
+            .unwrap();
+
+        assert_eq!(count, 17);
+        assert_eq!(buffer.filled_part(), b"cooperative yield");
+        assert_eq!(idle_calls, 3);
+    }
+    #[test]
+    fn shrink_filled_to() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"Hello, world");
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+
+        buffer.shrink_filled_to(5);
+        assert_eq!(buffer.filled_part(), b"Hello");
+        assert_eq!(buffer.initializer().items_initialized(), 5);
+        assert_eq!(buffer.unfilled_init_part(), b"");
+
+        buffer.shrink_filled_to(8);
+        assert_eq!(buffer.filled_part(), b"Hello");
+        assert_eq!(buffer.initializer().items_initialized(), 5);
+    }
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn buf_mut() {
+        use bytes::BufMut;
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut buffer_ref = buffer.by_ref();
+        assert_eq!(BufMut::remaining_mut(&buffer_ref), 16);
+
+        buffer_ref.put_slice(b"bytes");
+        buffer_ref.put_u8(b' ');
+        buffer_ref.put_u32(0x4275_664D);
+        buffer_ref.put_bytes(b'!', 2);
+        assert_eq!(buffer_ref.remaining_mut(), 4);
+
+        assert_eq!(buffer.filled_part(), b"bytes BufM!!");
+        assert_eq!(buffer.initializer().items_initialized(), 12);
+    }
+    #[test]
+    fn fill_remaining_cycled() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 12]);
+        buffer.append(b"> ");
+
+        buffer.fill_remaining_cycled(b"abc");
+        assert_eq!(buffer.filled_part(), b"> abcabcabca");
+        assert!(buffer.is_full());
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        buffer.fill_remaining_cycled(b"longer than the buffer");
+        assert_eq!(buffer.filled_part(), b"long");
+    }
+    #[test]
+    #[should_panic]
+    fn fill_remaining_cycled_empty_pattern() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        buffer.fill_remaining_cycled(b"");
+    }
+    #[test]
+    fn from_iter_bounded() {
+        let (buffer, dropped) = Buffer::<[MaybeUninit<u8>; 8]>::from_iter_bounded(1..=5);
+        assert_eq!(buffer.filled_part(), &[1, 2, 3, 4, 5]);
+        assert_eq!(buffer.remaining(), 3);
+        assert_eq!(dropped, 0);
+
+        let (buffer, dropped) = Buffer::<[MaybeUninit<u8>; 8]>::from_iter_bounded(1..=8);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.filled_part(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(dropped, 0);
+
+        let (buffer, dropped) =
+            Buffer::<[MaybeUninit<u8>; 8]>::from_iter_bounded(b"overflowing".iter().copied());
+        assert_eq!(buffer.filled_part(), b"overflow");
+        assert_eq!(dropped, 3);
+
+        let (buffer, dropped) = Buffer::<[MaybeUninit<u8>; 0]>::from_iter_bounded(1..=2);
+        assert!(buffer.is_empty());
+        assert_eq!(dropped, 2);
+    }
+    #[test]
+    fn from_iter_bounded_with_rest() {
+        let (buffer, mut rest) = Buffer::<[MaybeUninit<u8>; 8]>::from_iter_bounded_with_rest(1..=5);
+        assert_eq!(buffer.filled_part(), &[1, 2, 3, 4, 5]);
+        assert_eq!(rest.next(), None);
+
+        let (buffer, rest) = Buffer::<[MaybeUninit<u8>; 8]>::from_iter_bounded_with_rest(
+            b"overflowing".iter().copied(),
+        );
+        assert_eq!(buffer.filled_part(), b"overflow");
+        assert!(rest.eq(b"ing".iter().copied()));
+
+        let (buffer, mut rest) = Buffer::<[MaybeUninit<u32>; 4]>::from_iter_bounded_with_rest(0..);
+        assert_eq!(buffer.filled_part(), &[0, 1, 2, 3]);
+        assert_eq!(rest.next(), Some(4));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn map_filled_into_vec() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(&[1, 2, 3, 255]);
+
+        let mapped = buffer.map_filled_into_vec(|&byte| u32::from(byte) * 1000);
+        assert_eq!(mapped, [1000, 2000, 3000, 255_000]);
+    }
+    #[test]
+    fn put_integers() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+
+        buffer.put_u16_le(0x0102).unwrap();
+        buffer.put_u16_be(0x0102).unwrap();
+        buffer.put_u32_be(0xDEAD_BEEF).unwrap();
+        buffer.put_i64_le(-2).unwrap();
+        assert_eq!(
+            buffer.filled_part(),
+            &[2, 1, 1, 2, 0xDE, 0xAD, 0xBE, 0xEF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert!(buffer.is_full());
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 6]);
+        buffer.put_u16_le(7).unwrap();
+        assert_eq!(
+            buffer.put_i64_be(7),
+            Err(ExactError {
+                needed: 8,
+                available: 4
+            })
+        );
+        assert_eq!(buffer.filled_part(), &[7, 0]);
+        assert_eq!(buffer.initializer().items_initialized(), 2);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn map_into() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(&[1, 2, 0x80]);
+
+        let mapped = buffer.map_into(|byte| u16::from(byte) << 8);
+        assert_eq!(mapped.filled_part(), &[0x0100, 0x0200, 0x8000]);
+        assert!(mapped.is_full());
+        assert_eq!(mapped.into_vec(), [0x0100, 0x0200, 0x8000]);
+    }
+    #[test]
+    fn append_drain_from() {
+        let mut src = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut dst = Buffer::uninit([MaybeUninit::<u8>::uninit(); 6]);
+
+        src.append(b"staged pipeline");
+        assert_eq!(dst.append_drain_from(&mut src), 6);
+        assert_eq!(dst.filled_part(), b"staged");
+        assert_eq!(src.filled_part(), b" pipeline");
+        assert_eq!(src.remaining(), 7);
+
+        assert_eq!(dst.append_drain_from(&mut src), 0);
+
+        let mut dst = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        assert_eq!(dst.append_drain_from(&mut src), 9);
+        assert_eq!(dst.filled_part(), b" pipeline");
+        assert!(src.is_empty());
+    }
+    #[test]
+    fn partial_eq() {
+        let mut a = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut b = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        // Only the filled parts are compared, regardless of what else is initialized.
+        b.initializer_mut().zero_uninit_part();
+        assert_eq!(a, b);
+
+        a.append(b"hello");
+        assert_ne!(a, b);
+        b.append(b"hello");
+        assert_eq!(a, b);
+
+        assert_eq!(a, b"hello"[..]);
+        assert_eq!(a, &b"hello"[..]);
+        assert_ne!(a, &b"help"[..]);
+    }
+    #[test]
+    fn consume() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"read-ahead");
+
+        buffer.consume(5);
+        assert_eq!(buffer.consumed(), 5);
+        assert_eq!(buffer.readable(), b"ahead");
+        assert_eq!(buffer.filled_part(), b"read-ahead");
+
+        buffer.append(b"!");
+        buffer.consume(6);
+        assert_eq!(buffer.consumed(), 0);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.initializer().items_initialized(), 11);
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn buf_read() {
+        use std::io::{BufRead, Read};
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
+        buffer.append(b"first line\nsecond line\n");
+
+        let mut line = String::new();
+        buffer.read_line(&mut line).unwrap();
+        assert_eq!(line, "first line\n");
+        assert_eq!(buffer.consumed(), 11);
+
+        let mut rest = [0_u8; 6];
+        buffer.read_exact(&mut rest).unwrap();
+        assert_eq!(&rest, b"second");
+
+        assert_eq!(buffer.fill_buf().unwrap(), b" line\n");
+    }
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn assert_filled_eq() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 64]);
+        buffer.append(b"The quick brown fox jumps over the lazy dog");
+        buffer.assert_filled_eq(b"The quick brown fox jumps over the lazy dog");
+
+        let message = std::panic::catch_unwind(|| {
+            buffer.assert_filled_eq(b"The quick brown cat jumps over the lazy dog")
+        })
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+        assert!(message.contains("at offset 16"), "{}", message);
+        assert!(message.contains("filled[8..]"), "{}", message);
+
+        let message = std::panic::catch_unwind(|| buffer.assert_filled_eq(b"The quick"))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert!(message.contains("at offset 9"), "{}", message);
+    }
+    #[test]
+    fn guarded() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        {
+            let mut guard = buffer.guarded();
+            guard.append(b"guard");
+        }
+        assert_eq!(buffer.filled_part(), b"guard");
+    }
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn guarded_corrupted() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut guard = buffer.guarded();
+        // Deliberately mark uninitialized items as filled, which the guard must catch.
+        guard.items_filled = 4;
+    }
+    #[test]
+    fn fmt_write() {
+        use core::fmt::Write;
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        write!(buffer, "{}+{}={}", 40, 2, 40 + 2).unwrap();
+        buffer.write_char('→').unwrap();
+        assert_eq!(buffer.as_str(), Ok("40+2=42→"));
+
+        assert_eq!(write!(buffer, "{}", "overflow"), Err(fmt::Error));
+        assert_eq!(buffer.as_str(), Ok("40+2=42→"));
+
+        buffer.write_str("abcd").unwrap();
+        assert_eq!(buffer.write_char('€'), Err(fmt::Error));
+        buffer.write_char('!').unwrap();
+        assert_eq!(buffer.as_str(), Ok("40+2=42→abcd!"));
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn buf_read_refilled_from_reader() {
+        use std::io::BufRead;
+
+        let mut reader: &[u8] = b"alpha\nbeta\ngamma\n";
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 11]);
+        let mut lines = Vec::new();
+
+        // NOTE: fill_buf never reads by itself, so the buffer has to be refilled manually. Once
+        // everything has been consumed, the buffer is reset and can be refilled from the start.
+        loop {
+            buffer.read_from_bounded(&mut reader, 11).unwrap();
+            if buffer.fill_buf().unwrap().is_empty() {
+                break;
+            }
+            let mut line = String::new();
+            buffer.read_line(&mut line).unwrap();
+            lines.push(line);
+        }
+
+        assert_eq!(lines, ["alpha\n", "beta\n", "gamma\n"]);
+    }
+    #[test]
+    fn fold_filled() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(&[1, 2, 4, 8, 255]);
+        buffer.initializer_mut().zero_uninit_part();
+
+        let sum = buffer.fold_filled(0_u32, |sum, &byte| sum + u32::from(byte));
+        assert_eq!(sum, 270);
+
+        let parity = buffer.fold_filled(0_u8, |parity, &byte| parity ^ byte);
+        assert_eq!(parity, 0xF0);
+    }
+    #[test]
+    fn fill_by_default() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Sample {
+            value: i16,
+            valid: bool,
+        }
+        impl Default for Sample {
+            fn default() -> Self {
+                Self {
+                    value: -1,
+                    valid: false,
+                }
+            }
+        }
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<Sample>::uninit(); 4]);
+        buffer.append(&[Sample {
+            value: 7,
+            valid: true,
+        }]);
+        buffer.fill_by_default();
+
+        assert!(buffer.is_full());
+        assert_eq!(buffer.filled_part()[0].value, 7);
+        assert!(buffer.filled_part()[1..]
+            .iter()
+            .all(|sample| *sample == Sample::default()));
+    }
+    #[test]
+    fn pad_to_multiple_of() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 12]);
+
+        buffer.pad_to_multiple_of(4, 0).unwrap();
+        assert!(buffer.is_empty());
+
+        buffer.append(b"block");
+        buffer.pad_to_multiple_of(4, 0).unwrap();
+        assert_eq!(buffer.filled_part(), b"block\0\0\0");
+
+        buffer.pad_to_multiple_of(4, 0).unwrap();
+        assert_eq!(buffer.items_filled(), 8);
+
+        buffer.append(b"!");
+        assert_eq!(buffer.pad_to_multiple_of(8, 0), Err(4));
+        assert_eq!(buffer.items_filled(), 9);
+        assert_eq!(buffer.initializer().items_initialized(), 9);
+    }
+    #[test]
+    fn spare_capacity_mut() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(b"ab");
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+
+        let spare = buffer.spare_capacity_mut();
+        assert_eq!(spare.len(), 4);
+        crate::fill_uninit_slice(&mut spare[..3], b'c');
+
+        unsafe {
+            buffer.initializer_mut().advance(3);
+        }
+        assert_eq!(buffer.unfilled_init_part(), b"\0\0ccc");
+        assert_eq!(buffer.spare_capacity_mut().len(), 1);
+    }
+    #[test]
+    fn forget_init_past() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"head");
+        buffer.initializer_mut().partially_zero_uninit_part(8);
+        assert_eq!(buffer.unfilled_uninit_part().len(), 4);
+
+        buffer.forget_init_past(6);
+        assert_eq!(buffer.initializer().items_initialized(), 6);
+        assert_eq!(buffer.unfilled_uninit_part().len(), 10);
+
+        buffer.forget_init_past(0);
+        assert_eq!(buffer.initializer().items_initialized(), 4);
+        assert_eq!(buffer.filled_part(), b"head");
+
+        buffer.forget_init_past(12);
+        assert_eq!(buffer.initializer().items_initialized(), 4);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn map_backing() {
+        let mut buffer = Buffer::uninit(vec![MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(b"backing");
+        buffer.consume(4);
+
+        // SAFETY: Converting the vector into a boxed slice keeps both its length and contents.
+        let buffer: Buffer<Box<[MaybeUninit<u8>]>> =
+            unsafe { buffer.map_backing(Vec::into_boxed_slice) };
+        assert_eq!(buffer.filled_part(), b"backing");
+        assert_eq!(buffer.readable(), b"ing");
+        assert_eq!(buffer.initializer().items_initialized(), 7);
+        assert_eq!(buffer.capacity(), 8);
+    }
+    #[test]
+    fn all_bytes_maybe_uninit() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let ptr = buffer.all_bytes_maybe_uninit().as_ptr();
+        assert_eq!(buffer.all_bytes_maybe_uninit().len(), 16);
+
+        buffer.append(b"cursors");
+        buffer.initializer_mut().partially_zero_uninit_part(3);
+        assert_eq!(buffer.all_bytes_maybe_uninit().len(), 16);
+        assert_eq!(buffer.all_bytes_maybe_uninit().as_ptr(), ptr);
+    }
+    #[test]
+    fn try_take_frame() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
+
+        buffer.append(&[0, 5]);
+        assert_eq!(buffer.try_take_frame(2), None);
+        buffer.append(b"hel");
+        assert_eq!(buffer.try_take_frame(2), None);
+        assert_eq!(buffer.consumed(), 0);
+
+        buffer.append(b"lo");
+        buffer.append(&[0, 0]);
+        buffer.append(&[0, 3]);
+        buffer.append(b"ab");
+
+        assert_eq!(buffer.try_take_frame(2), Some(&b"hello"[..]));
+        assert_eq!(buffer.try_take_frame(2), Some(&b""[..]));
+        assert_eq!(buffer.try_take_frame(2), None);
+        assert_eq!(buffer.readable(), &[0, 3, b'a', b'b']);
+
+        buffer.append(b"c");
+        assert_eq!(buffer.try_take_frame(2), Some(&b"abc"[..]));
+        assert!(buffer.is_empty());
+
+        buffer.append(&[1, b'x']);
+        assert_eq!(buffer.try_take_frame(1), Some(&b"x"[..]));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_and_split_frame() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+
+        // Incomplete prefix.
+        buffer.append(&[0]);
+        assert_eq!(buffer.verify_and_split_frame(PrefixWidth::U16), None);
+
+        // Incomplete payload.
+        buffer.append(&[5, b'h', b'e']);
+        assert_eq!(buffer.verify_and_split_frame(PrefixWidth::U16), None);
+        assert_eq!(buffer.filled_part(), &[0, 5, b'h', b'e']);
+
+        // Complete frame, followed by the start of the next one.
+        buffer.append(b"llo");
+        buffer.append(&[0, 0, 0, 2, b'a']);
+        assert_eq!(
+            buffer.verify_and_split_frame(PrefixWidth::U16).as_deref(),
+            Some(&b"hello"[..])
+        );
+        assert_eq!(buffer.filled_part(), &[0, 0, 0, 2, b'a']);
+        assert_eq!(buffer.consumed(), 0);
+
+        buffer.append(b"b");
+        assert_eq!(
+            buffer.verify_and_split_frame(PrefixWidth::U32).as_deref(),
+            Some(&b"ab"[..])
+        );
+        assert!(buffer.is_empty());
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_filled_vec() {
+        use alloc::rc::Rc;
+        use alloc::string::String;
+
+        let shared = Rc::new(String::from("owned"));
+
+        let mut backing = Vec::with_capacity(8);
+        backing.resize_with(8, MaybeUninit::uninit);
+        let mut buffer = Buffer::uninit(backing);
+
+        unsafe {
+            for item in &mut buffer.unfilled_part_mut()[..5] {
+                *item = MaybeUninit::new(Rc::clone(&shared));
+            }
+            buffer.initializer_mut().advance(5);
+        }
+        // Leave two of the items initialized but not filled.
+        buffer.advance(3);
+        assert_eq!(Rc::strong_count(&shared), 6);
+
+        let vec = buffer.into_filled_vec();
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&shared), 4);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+    #[test]
+    fn append_vectored() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+
+        assert_eq!(
+            buffer.append_vectored(&[b"GET ", b"/index.html", b" HTTP/1.1"]),
+            16
+        );
+        assert_eq!(buffer.filled_part(), b"GET /index.html ");
+
+        assert_eq!(buffer.append_vectored(&[b"", b"more"]), 0);
+    }
+    #[test]
+    fn append_from_within_backing() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"abc");
+
+        buffer.append_from_within_backing(0..3);
+        assert_eq!(buffer.filled_part(), b"abcabc");
+
+        buffer.append_from_within_backing(4..6);
+        assert_eq!(buffer.filled_part(), b"abcabcbc");
+
+        // The source overlaps with the destination, which is initialized but not yet filled.
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+        buffer.append_from_within_backing(6..10);
+        assert_eq!(buffer.filled_part(), b"abcabcbcbc\0\0");
+        assert_eq!(buffer.initializer().items_initialized(), 12);
+    }
+    #[test]
+    #[should_panic]
+    fn append_from_within_backing_uninit() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"abc");
+        buffer.append_from_within_backing(2..4);
+    }
+    #[test]
+    fn rotate_filled_left() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"Hello, world");
+        buffer.initializer_mut().partially_fill_uninit_part(2, b'!');
+
+        buffer.rotate_filled_left(0);
+        assert_eq!(buffer.filled_part(), b"Hello, world");
+        assert_eq!(buffer.initializer().items_initialized(), 14);
+
+        // The items being moved overlap with their destination.
+        buffer.rotate_filled_left(3);
+        assert_eq!(buffer.filled_part(), b"lo, world");
+        assert_eq!(buffer.initializer().items_initialized(), 11);
+        assert_eq!(buffer.unfilled_init_part(), b"rl");
+
+        buffer.rotate_filled_left(buffer.items_filled());
+        assert_eq!(buffer.filled_part(), b"");
+        assert_eq!(buffer.initializer().items_initialized(), 2);
+        assert_eq!(buffer.unfilled_init_part(), b"lo");
+    }
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_filled_cow() {
+        let mut storage = [MaybeUninit::<u8>::uninit(); 16];
+        let mut buffer = Buffer::uninit(&mut storage[..]);
+        buffer.append(b"borrowed");
+        let cow = buffer.into_filled_cow();
+        assert!(matches!(cow, Cow::Borrowed(b"borrowed")));
+
+        let mut storage = *b"borrowed, initially";
+        let buffer = Buffer::from_slice_with_filled(&mut storage[..], 8);
+        let cow = buffer.into_filled_cow();
+        assert!(matches!(cow, Cow::Borrowed(b"borrowed")));
+
+        let mut buffer = Buffer::from_vec(Vec::with_capacity(16));
+        buffer.append(b"owned");
+        let cow = buffer.into_filled_cow();
+        assert!(matches!(&cow, Cow::Owned(vec) if vec == b"owned"));
+    }
+    #[test]
+    fn append_all_from() {
+        let mut src: &[u8] = b"drained across buffers";
+        let mut buffers = [[MaybeUninit::<u8>::uninit(); 8]; 3];
+        let mut filled = Vec::new();
+
+        for storage in &mut buffers {
+            let mut buffer = Buffer::uninit(&mut storage[..]);
+            let count = buffer.append_all_from(&mut src);
+            assert_eq!(count, buffer.items_filled());
+            filled.extend_from_slice(buffer.filled_part());
+        }
+        assert_eq!(filled, b"drained across buffers");
+        assert!(src.is_empty());
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        assert_eq!(buffer.append_all_from(&mut src), 0);
+    }
+    #[test]
+    fn iter_filled() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(&[1, 2, 3]);
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+
+        assert!(buffer.iter_filled().eq(&[1, 2, 3]));
 
-        let BufferPartsMut {
-            filled_part,
-            unfilled_init_part,
-            unfilled_uninit_part,
-        } = buffer.all_parts_mut();
-        assert_eq!(filled_part, &mut []);
-        assert_eq!(unfilled_init_part, &mut []);
-        assert_eq!(unfilled_uninit_part.len(), 32);
+        for item in &mut buffer {
+            *item *= 2;
+        }
+        let mut sum = 0;
+        for item in &buffer {
+            sum += item;
+        }
+        assert_eq!(sum, 12);
+    }
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_iter_owned() {
+        use alloc::rc::Rc;
 
-        let src = b"I am a really nice slice!";
-        let modified = b"I am a really wise slice!";
+        let item = Rc::new(());
+        let (mut buffer, _) =
+            Buffer::<[MaybeUninit<Rc<()>>; 4]>::from_iter_bounded(vec![Rc::clone(&item); 3]);
+        // Move the filled cursor back, leaving an initialized but unfilled item.
+        buffer.items_filled = 2;
+        assert_eq!(Rc::strong_count(&item), 4);
 
-        buffer.append(src);
+        let mut iter = buffer.into_iter();
+        assert_eq!(Rc::strong_count(&item), 3);
+        assert_eq!(iter.len(), 2);
 
-        assert!(!buffer.is_empty());
-        assert!(!buffer.is_full());
-        assert_eq!(buffer.filled_part(), src);
-        assert_eq!(buffer.filled_part_mut(), src);
-        assert!(!buffer.initializer().is_completely_init());
-        assert!(!buffer.initializer().is_completely_uninit());
-        assert_eq!(buffer.initializer().init_part(), src);
-        assert_eq!(buffer.initializer_mut().init_part_mut(), src);
-        assert_eq!(buffer.items_filled(), src.len());
-        assert_eq!(buffer.remaining(), 32 - src.len());
+        drop(iter.next().unwrap());
+        assert_eq!(Rc::strong_count(&item), 2);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_initialized_backings() {
+        let mut buffer = Buffer::new(vec![0_u8; 16]);
+        assert_eq!(buffer.initializer().items_initialized(), 16);
+        buffer.append(b"vector");
+        assert_eq!(buffer.filled_part(), b"vector");
+        assert_eq!(buffer.remaining(), 10);
+
+        let mut buffer = Buffer::new(vec![0_u8; 16].into_boxed_slice());
+        buffer.append(b"boxed slice");
+        assert_eq!(buffer.into_inner().0[..11], b"boxed slice"[..]);
+    }
+    #[test]
+    fn append_reusing_init_part() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"first cycle");
+        buffer.items_filled = 0;
+        assert_eq!(buffer.reusable_init_len(), 11);
+
+        buffer.append(b"second");
+        assert_eq!(buffer.filled_part(), b"second");
+        assert_eq!(buffer.initializer().items_initialized(), 11);
+        assert_eq!(buffer.reusable_init_len(), 5);
+
+        // Spill over from the initialized part into the uninitialized part.
+        buffer.append(b" cycle!");
+        assert_eq!(buffer.filled_part(), b"second cycle!");
+        assert_eq!(buffer.initializer().items_initialized(), 13);
+        assert_eq!(buffer.reusable_init_len(), 0);
+    }
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn heapless_spare_capacity() {
+        let mut vec = heapless::Vec::<u8, 16>::new();
+
+        let mut buffer = Buffer::from_heapless_spare_capacity(&mut vec);
+        assert_eq!(buffer.capacity(), 16);
+        buffer.append(b"heap");
+        assert_eq!(buffer.commit_to_vec(), 4);
+        assert_eq!(&vec[..], b"heap");
+
+        let mut buffer = Buffer::from_heapless_spare_capacity(&mut vec);
+        assert_eq!(buffer.capacity(), 12);
+        buffer.append(b"less");
+        assert_eq!(buffer.commit_to_vec(), 4);
+        assert_eq!(&vec[..], b"heapless");
+    }
+    #[test]
+    fn freeze() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(b"part");
+
+        let mut buffer = match buffer.try_freeze() {
+            Ok(_) => panic!("froze a partially filled buffer"),
+            Err(buffer) => buffer,
+        };
+        assert_eq!(buffer.filled_part(), b"part");
+
+        buffer.append(b"ial!");
+        let frozen = buffer.try_freeze().unwrap();
+        assert_eq!(&*frozen, b"partial!");
+
+        let buffer = Buffer::from_init_array(*b"complete");
+        assert_eq!(&*buffer.freeze(), b"complete");
+    }
+    #[test]
+    #[should_panic]
+    fn freeze_partial() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.append(b"part");
+        let _ = buffer.freeze();
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split_off_filled() {
+        let mut buffer = Buffer::with_capacity(16);
+        buffer.append(b"frame");
+        buffer.initializer_mut().partially_fill_uninit_part(3, b'!');
+
+        let (frame, mut rest) = buffer.split_off_filled();
+        assert_eq!(frame.into_init_vec(), b"frame");
+        assert_eq!(rest.capacity(), 11);
+        assert_eq!(rest.items_filled(), 0);
+        assert_eq!(rest.unfilled_init_part(), b"!!!");
+
+        rest.append(b"next");
+        assert_eq!(rest.filled_part(), b"next");
+
+        let mut buffer = Buffer::with_boxed_capacity(8);
+        buffer.append(b"boxed");
+
+        let (frame, rest) = buffer.split_off_filled();
+        assert_eq!(&*frame.into_init_box(), b"boxed");
+        assert_eq!(rest.capacity(), 3);
+        assert_eq!(rest.initializer().items_initialized(), 0);
+    }
+    #[test]
+    fn drain_filled_to() {
+        let mut src = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        src.append(b"flush me please");
+        let mut dst = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        dst.append(b"> ");
+
+        assert_eq!(src.drain_filled_to(&mut dst), 6);
+        assert_eq!(dst.filled_part(), b"> flush ");
+        assert_eq!(src.filled_part(), b"me please");
+        assert_eq!(src.initializer().items_initialized(), 9);
+
+        assert_eq!(src.drain_filled_to(&mut dst), 0);
+        assert_eq!(src.filled_part(), b"me please");
+    }
+    #[test]
+    fn extend() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        buffer.extend(b"ab".iter());
+        buffer.extend(b'c'..=b'e');
+        assert_eq!(buffer.filled_part(), b"abcde");
+
+        // The items that do not fit are discarded.
+        buffer.extend(b"fghij");
+        assert_eq!(buffer.filled_part(), b"abcdefgh");
+        assert!(buffer.is_full());
+    }
+    #[test]
+    fn try_extend() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        assert!(buffer.try_extend(1..=4));
+        assert!(buffer.try_extend(5..=8));
+        assert!(buffer.is_full());
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut iter = 1..=10;
+        assert!(!buffer.try_extend(&mut iter));
+        assert_eq!(buffer.filled_part(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(iter.next(), Some(10));
+    }
+    #[test]
+    fn fill_cursor() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"> ");
+        buffer.initializer_mut().partially_zero_uninit_part(4);
 
         {
-            let slice_mut = buffer.filled_part_mut();
-            slice_mut[14] = b'w';
-            slice_mut[16] = b's';
+            let cursor = buffer.fill_cursor();
+            assert_eq!(cursor.len(), 14);
         }
+        assert_eq!(buffer.initializer().items_initialized(), 6);
 
-        assert!(!buffer.is_empty());
-        assert!(!buffer.is_full());
-        assert_eq!(buffer.filled_part(), modified);
-        assert_eq!(buffer.filled_part_mut(), modified);
-        assert_eq!(buffer.initializer().init_part(), modified);
-        assert_eq!(buffer.initializer_mut().init_part_mut(), modified);
-        assert_eq!(buffer.items_filled(), src.len());
-        assert_eq!(buffer.remaining(), 32 - src.len());
-        assert_eq!(buffer.unfilled_part().len(), 32 - src.len());
-        assert_eq!(unsafe { buffer.unfilled_part_mut().len() }, 32 - src.len());
+        {
+            let mut cursor = buffer.fill_cursor();
 
-        let mut initializer = buffer.into_initializer();
+            cursor[..5].copy_from_slice(crate::cast_init_to_uninit_slice(b"hello"));
+            unsafe { cursor.record(5) };
+            assert_eq!(cursor.recorded(), 5);
+        }
+        assert_eq!(buffer.filled_part(), b"> hello");
+        assert_eq!(buffer.initializer().items_initialized(), 7);
 
-        assert_eq!(initializer.items_initialized(), modified.len());
-        assert_eq!(initializer.remaining(), 7);
-        initializer.partially_fill_uninit_part(3_usize, 0xFF_u8);
-        initializer.partially_zero_uninit_part(1_usize);
-        assert_eq!(initializer.remaining(), 3);
+        let mut cursor = buffer.fill_cursor();
+        cursor[..6].copy_from_slice(crate::cast_init_to_uninit_slice(b" world"));
+        unsafe { cursor.commit(3) };
+        assert_eq!(buffer.filled_part(), b"> hello wo");
 
-        let modified_and_garbage_items = b"I am a really wise slice!\xFF\xFF\xFF\x00";
-        let (init, uninit) = initializer.init_uninit_parts();
-        assert_eq!(init, modified_and_garbage_items);
-        assert_eq!(uninit.len(), 3);
+        // Nothing is advanced unless recorded.
+        let mut cursor = buffer.fill_cursor();
+        cursor[0] = MaybeUninit::new(b'!');
+        drop(cursor);
+        assert_eq!(buffer.filled_part(), b"> hello wo");
 
-        let (init, uninit) = initializer.init_uninit_parts_mut();
-        assert_eq!(init, modified_and_garbage_items);
-        init[2] = b'e';
-        assert_eq!(uninit.len(), 3);
+        // Items of the initialized-but-unfilled part can be recorded without being written.
+        buffer.initializer_mut().partially_fill_uninit_part(3, b'!');
+        let cursor = buffer.fill_cursor();
+        unsafe { cursor.commit(2) };
+        assert_eq!(buffer.filled_part(), b"> hello wo!!");
+    }
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn smallvec_backing() {
+        use smallvec::{smallvec, SmallVec};
 
-        let mut buffer = Buffer::from_initializer(initializer);
-        buffer.advance(modified.len());
+        let inline: SmallVec<[MaybeUninit<u8>; 8]> = smallvec![MaybeUninit::uninit(); 4];
+        let mut buffer = Buffer::uninit(inline);
+        buffer.append(b"tiny");
+        let vec = SmallVec::<[u8; 8]>::from(buffer.freeze());
+        assert!(!vec.spilled());
+        assert_eq!(&vec[..], b"tiny");
 
-        let BufferParts {
-            filled_part,
-            unfilled_init_part,
-            unfilled_uninit_part,
-        } = buffer.all_parts();
-        let modified_again = b"I em a really wise slice!";
-        assert_eq!(filled_part, modified_again);
-        assert_eq!(unfilled_init_part, b"\xFF\xFF\xFF\x00");
-        assert_eq!(unfilled_uninit_part.len(), 3);
+        let spilled: SmallVec<[MaybeUninit<u8>; 8]> = smallvec![MaybeUninit::uninit(); 32];
+        let mut buffer = Buffer::uninit(spilled);
+        buffer.fill_remaining_cycled(b"large ");
+        let vec = SmallVec::<[u8; 8]>::from(buffer.freeze());
+        assert!(vec.spilled());
+        assert_eq!(&vec[..], b"large large large large large la");
+    }
+    #[test]
+    fn chain() {
+        let mut first = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        first.append(b"head");
+        let mut storage = [0_u8; 16];
+        let mut second = Buffer::new(&mut storage[..]);
+        second.append(b", tail");
 
-        let BufferPartsMut {
-            filled_part,
-            unfilled_init_part,
-            unfilled_uninit_part,
-        } = buffer.all_parts_mut();
-        assert_eq!(filled_part, modified_again);
-        assert_eq!(unfilled_init_part, b"\xFF\xFF\xFF\x00");
-        unfilled_init_part[2] = b'\x13';
-        unfilled_init_part[3] = b'\x37';
-        assert_eq!(unfilled_init_part, b"\xFF\xFF\x13\x37");
-        assert_eq!(unfilled_uninit_part.len(), 3);
+        let lengths = first.chain(&second).map(<[u8]>::len);
+        assert!(lengths.eq([4, 6].iter().copied()));
 
-        let rest = b" Right?";
-        buffer.append(rest);
+        let empty = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut chained = empty.chain(&second);
+        assert_eq!(chained.next(), Some(&b", tail"[..]));
+        assert_eq!(chained.next(), None);
+    }
+    #[test]
+    fn fill_remaining_with() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u32>::uninit(); 6]);
+        buffer.append(&[0]);
 
-        assert_eq!(buffer.items_filled(), 32);
-        assert!(!buffer.is_empty());
-        assert!(buffer.is_full());
-        assert_eq!(buffer.remaining(), 0);
+        let mut next = 1;
+        buffer.fill_remaining_with(|| {
+            next *= 2;
+            next
+        });
+        assert_eq!(buffer.filled_part(), &[0, 2, 4, 8, 16, 32]);
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_remaining_with_panic() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
 
-        let total = b"I em a really wise slice! Right?";
-        assert_eq!(buffer.filled_part(), total);
-        assert_eq!(buffer.filled_part_mut(), total);
-        assert_eq!(buffer.initializer().remaining(), 0);
-        assert_eq!(buffer.initializer().items_initialized(), 32);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut count = 0;
+            buffer.fill_remaining_with(|| {
+                count += 1;
+                assert!(count <= 3, "generator exhausted");
+                count
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(buffer.filled_part(), &[1, 2, 3]);
+        assert_eq!(buffer.initializer().items_initialized(), 3);
+    }
+    #[test]
+    fn set_filled() {
+        let mut storage = [MaybeUninit::<u8>::uninit(); 16];
+        let mut buffer = Buffer::uninit(&mut storage[..]);
+
+        // Simulate a system call writing directly to the backing memory.
+        unsafe {
+            buffer.unfilled_part_mut()[..5]
+                .copy_from_slice(crate::cast_init_to_uninit_slice(b"hello"));
+            buffer.set_filled(5);
+        }
+        assert_eq!(buffer.filled_part(), b"hello");
+        assert_eq!(buffer.initializer().items_initialized(), 5);
+
+        unsafe { buffer.set_filled(2) };
+        assert_eq!(buffer.filled_part(), b"he");
+        assert_eq!(buffer.unfilled_init_part(), b"llo");
+    }
+    #[test]
+    fn advance_over_gap_if() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"abc");
+        buffer.initializer_mut().partially_fill_uninit_part(4, b'd');
+
+        buffer.advance_over_gap_if(|gap| gap == b"eeee");
+        assert_eq!(buffer.filled_part(), b"abc");
+
+        buffer.advance_over_gap_if(|gap| gap == b"dddd");
+        assert_eq!(buffer.filled_part(), b"abcdddd");
+        assert_eq!(buffer.unfilled_init_part(), &[]);
+    }
+    #[test]
+    fn from_slice_mut_generic() {
+        let mut integers = [1_u32, 2, 3, 4];
+        let mut buffer = Buffer::from_slice_mut(&mut integers[..]);
         assert!(buffer.initializer().is_completely_init());
-        assert!(!buffer.initializer().is_completely_uninit());
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.unfilled_init_part(), &[1, 2, 3, 4]);
+
+        buffer.append(&[5, 6]);
+        assert_eq!(buffer.filled_part(), &[5, 6]);
+        assert_eq!(integers, [5, 6, 3, 4]);
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct MyStruct {
+            a: u16,
+            b: char,
+        }
+        let mut structs = [MyStruct { a: 0, b: 'a' }; 3];
+        let mut buffer = Buffer::from_slice_mut(&mut structs[..]);
+        buffer.advance(1);
+        buffer.append(&[MyStruct { a: 1, b: 'b' }]);
+        assert_eq!(
+            buffer.filled_part(),
+            &[MyStruct { a: 0, b: 'a' }, MyStruct { a: 1, b: 'b' }]
+        );
+        assert_eq!(buffer.remaining(), 1);
+    }
+    #[test]
+    fn copy_from_at() {
+        let mut first = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        first.append(b"abcd");
+        let mut second = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        second.append(b"ef");
+        let mut third = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        third.append(b"ghij");
+
+        // Contiguous destinations.
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.copy_from_at(0, &first);
+        buffer.copy_from_at(4, &second);
+        assert_eq!(buffer.filled_part(), b"abcdef");
+        assert_eq!(buffer.initializer().items_initialized(), 6);
 
+        // Gapped destinations, where the gap is filled afterwards.
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.copy_from_at(0, &first);
+        buffer.initializer_mut().partially_zero_uninit_part(2);
+        buffer.copy_from_at(6, &third);
+        assert_eq!(buffer.filled_part(), b"abcd");
+        assert_eq!(buffer.initializer().init_part(), b"abcd\0\0ghij");
+
+        buffer.copy_from_at(4, &second);
+        assert_eq!(buffer.filled_part(), b"abcdef");
         buffer.advance_to_init_part();
+        assert_eq!(buffer.filled_part(), b"abcdefghij");
+    }
+    #[test]
+    #[should_panic]
+    fn copy_from_at_beyond_init() {
+        let mut src = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        src.append(b"abcd");
 
-        // TODO: Shorthand?
-        let initialized: [u8; 32] = buffer.into_initializer().try_into_init().unwrap().into();
-        assert_eq!(&initialized, total);
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.copy_from_at(1, &src);
     }
     #[test]
-    fn debug_impl() {
-        let array = [MaybeUninit::<u8>::uninit(); 32];
-        let mut buffer = Buffer::uninit(array);
+    fn copy_from_buffer() {
+        let mut src = Buffer::new(vec![0_u8; 12]);
+        src.advance(4);
+        src.append(b"abcdefgh");
+
+        let mut dst = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        dst.append(b"xy");
+        assert_eq!(dst.copy_from_buffer(&src), 6);
+        assert_eq!(dst.filled_part(), b"xy\0\0\0\0ab");
+        assert!(dst.is_full());
+        assert_eq!(dst.copy_from_buffer(&src), 0);
+
+        let mut src = Buffer::uninit([MaybeUninit::<u8>::uninit(); 4]);
+        src.append(b"1234");
+        let mut dst = Buffer::new(vec![0_u8; 8]);
+        assert_eq!(dst.copy_from_buffer(&src), 4);
+        assert_eq!(dst.filled_part(), b"1234");
+    }
+    #[test]
+    fn from_vec() {
+        let mut vec = Vec::with_capacity(16);
+        vec.extend_from_slice(b"abc");
+        let ptr = vec.as_ptr();
+        let capacity = vec.capacity();
+
+        let mut buffer = Buffer::from_vec(vec);
+        assert_eq!(buffer.capacity(), capacity);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.unfilled_init_part(), b"abc");
+
+        buffer.append(b"Hello");
+        let vec = buffer.into_vec();
+        assert_eq!(vec, b"Hello");
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), capacity);
+
+        let mut buffer = Buffer::from_vec(vec);
+        buffer.advance(3);
+        assert_eq!(buffer.into_vec(), b"Hel");
+    }
+    #[test]
+    fn into_boxed_filled() {
+        let mut buffer = Buffer::from_vec(Vec::with_capacity(64));
         buffer.append(b"Hello, world!");
-        buffer.initializer_mut().partially_zero_uninit_part(13);
 
-        assert_eq!(
-            format!("{:?}", buffer),
-            format!(
-                "[buffer at {:p}, 13/26/32]",
-                buffer.initializer().all_uninit().as_ptr()
-            )
-        );
-        assert_eq!(
-            format!("{:#?}", buffer),
-            format!(
-                "[buffer at {:p}, 13 B filled (40.6%), 26 B init (81.2%), 32 B total]",
-                buffer.initializer().all_uninit().as_ptr()
-            )
-        );
+        let boxed = buffer.into_boxed_filled();
+        assert_eq!(boxed.len(), 13);
+        assert_eq!(&*boxed, b"Hello, world!");
+    }
+    #[test]
+    fn grow() {
+        let mut buffer = Buffer::from_vec(Vec::with_capacity(4));
+        buffer.append(b"abcd");
+        assert_eq!(buffer.remaining(), 0);
+
+        buffer.grow(8);
+        assert_eq!(buffer.capacity(), 12);
+        assert_eq!(buffer.items_filled(), 4);
+        assert_eq!(buffer.initializer().items_initialized(), 4);
+
+        buffer.append(b"efghijkl");
+        assert!(buffer.is_full());
+        assert_eq!(buffer.filled_part(), b"abcdefghijkl");
+    }
+    #[test]
+    fn shrink_to_fit() {
+        let mut buffer = Buffer::from_vec(Vec::with_capacity(1024));
+        buffer.append(b"Hello");
+        buffer.initializer_mut().partially_zero_uninit_part(3);
+
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.capacity(), 8);
+        assert_eq!(buffer.filled_part(), b"Hello");
+        assert_eq!(buffer.unfilled_init_part(), b"\0\0\0");
+        assert!(buffer.initializer().is_completely_init());
+
+        let vec = buffer.into_vec();
+        assert_eq!(vec, b"Hello");
+        assert!(vec.capacity() < 1024);
+    }
+    #[test]
+    fn part_offsets() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 32]);
+        assert_eq!(buffer.part_offsets(), (0..0, 0..0, 0..32));
+
+        buffer.append(b"Hello");
+        buffer.initializer_mut().partially_zero_uninit_part(10);
+        assert_eq!(buffer.part_offsets(), (0..5, 5..15, 15..32));
+
+        let (filled, unfilled_init, unfilled_uninit) = buffer.part_offsets();
+        let parts = buffer.all_parts();
+        assert_eq!(filled.len(), parts.filled_part.len());
+        assert_eq!(unfilled_init.len(), parts.unfilled_init_part.len());
+        assert_eq!(unfilled_uninit.len(), parts.unfilled_uninit_part.len());
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn split() {
+        use std::io::{Read, Write};
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"Hello");
+
+        {
+            let (mut reader, mut writer) = buffer.split();
+            let mut dst = [0_u8; 3];
+
+            reader.read_exact(&mut dst).unwrap();
+            assert_eq!(&dst, b"Hel");
+            writer.write_all(b", world").unwrap();
+            assert_eq!(reader.remaining_part(), b"lo");
+
+            reader.read_exact(&mut dst[..2]).unwrap();
+            assert_eq!(&dst[..2], b"lo");
+            assert_eq!(reader.read(&mut dst).unwrap(), 0);
+
+            assert_eq!(writer.write(b"! Goodbye!").unwrap(), 4);
+            assert_eq!(writer.remaining(), 0);
+            assert_eq!(writer.write(b"!").unwrap(), 0);
+        }
+
+        assert_eq!(buffer.filled_part(), b"Hello, world! Go");
+        assert!(buffer.is_full());
+        assert!(buffer.initializer().is_completely_init());
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_exact_from_reader() {
+        use std::io::Read;
+
+        struct Interrupting<'a>(&'a [u8], bool);
+
+        impl Read for Interrupting<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.1 = !self.1;
+
+                if self.1 {
+                    Err(std::io::ErrorKind::Interrupted.into())
+                } else {
+                    let count = core::cmp::min(buf.len(), 2);
+                    (&mut self.0).read(&mut buf[..count])
+                }
+            }
+        }
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut reader = Interrupting(b"\x05helloworld", false);
+
+        buffer.fill_exact_from_reader(&mut reader, 1).unwrap();
+        assert_eq!(buffer.filled_part(), b"\x05");
+
+        buffer.fill_exact_from_reader(&mut reader, 5).unwrap();
+        assert_eq!(buffer.filled_part(), b"\x05hello");
+        assert_eq!(reader.0, b"world");
+
+        let error = buffer.fill_exact_from_reader(&mut reader, 8).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(buffer.filled_part(), b"\x05helloworld");
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_exact_from_reader_until_full() {
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut reader: &[u8] = b"exactly!";
+        let remaining = buffer.remaining();
+        buffer
+            .fill_exact_from_reader(&mut reader, remaining)
+            .unwrap();
+        assert!(buffer.is_full());
+        assert_eq!(buffer.filled_part(), b"exactly!");
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+        let mut reader: &[u8] = b"short";
+        let remaining = buffer.remaining();
+        let error = buffer
+            .fill_exact_from_reader(&mut reader, remaining)
+            .unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(buffer.filled_part(), b"short");
+    }
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn with_borrowed_buf() {
+        use std::io::Read;
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.append(b"abc");
+
+        let mut reader: &[u8] = b"defghi";
+        buffer
+            .with_borrowed_buf(|borrowed_buf| {
+                assert_eq!(borrowed_buf.filled(), b"abc");
+                assert!(!borrowed_buf.is_init());
+                reader.read_buf(borrowed_buf.unfilled())
+            })
+            .unwrap();
+        assert_eq!(buffer.filled_part(), b"abcdefghi");
+        assert_eq!(buffer.initializer().items_initialized(), 9);
+
+        let mut storage = [0_u8; 8];
+        let mut buffer = Buffer::new(&mut storage[..]);
+        let mut reader: &[u8] = b"jklmnopqrstu";
+        buffer
+            .with_borrowed_buf(|borrowed_buf| {
+                assert!(borrowed_buf.is_init());
+                reader.read_buf(borrowed_buf.unfilled())
+            })
+            .unwrap();
+        assert_eq!(buffer.filled_part(), b"jklmnopq");
+        assert!(buffer.initializer().is_completely_init());
+    }
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn read_buf_from() {
+        use std::io::{BorrowedCursor, Read};
+
+        // A reader which only ever writes through read_buf, never requiring initialized memory.
+        struct Uninitializing<'a>(&'a [u8]);
+
+        impl Read for Uninitializing<'_> {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                unreachable!("read_buf should have been used")
+            }
+            fn read_buf(&mut self, mut cursor: BorrowedCursor<'_>) -> std::io::Result<()> {
+                let count = core::cmp::min(cursor.capacity(), self.0.len());
+                cursor.append(&self.0[..count]);
+                self.0 = &self.0[count..];
+                Ok(())
+            }
+        }
+
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        let mut reader = Uninitializing(b"without zeroing");
+        buffer.read_buf_from(&mut reader).unwrap();
+        assert_eq!(buffer.filled_part(), b"without zeroing");
+        assert_eq!(buffer.initializer().items_initialized(), 15);
+
+        // Only the initialized-but-unfilled part is read into, when there is one.
+        let mut buffer = Buffer::uninit([MaybeUninit::<u8>::uninit(); 16]);
+        buffer.initializer_mut().partially_zero_uninit_part(4);
+        let mut reader: &[u8] = b"reused";
+        buffer.read_buf_from(&mut reader).unwrap();
+        assert_eq!(buffer.filled_part(), b"reus");
+        assert_eq!(buffer.initializer().items_initialized(), 4);
+
+        buffer.read_buf_from(&mut reader).unwrap();
+        assert_eq!(buffer.filled_part(), b"reused");
     }
 }
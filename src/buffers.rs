@@ -453,7 +453,7 @@ where
             core::cmp::Ordering::Greater => unsafe { core::hint::unreachable_unchecked() },
         }
 
-        self.items_filled_for_vector += end;
+        self.items_filled_for_vector = end;
 
         if self.items_filled_for_vector == current_vector_all_len {
             self.vectors_filled += 1;
@@ -485,6 +485,124 @@ where
         self.vectors_filled += 1;
         self.items_filled_for_vector = 0;
     }
+    /// Get the number of items that may be filled before all vectors are full.
+    ///
+    /// Note that like with [`count_remaining_items_to_fill`], this is O(n), where `n` is the
+    /// number of vectors that have not yet been filled.
+    ///
+    /// [`count_remaining_items_to_fill`]: Self::count_remaining_items_to_fill
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.count_remaining_items_to_fill()
+    }
+    /// Mark `count` items of the current vector as both filled and initialized, moving on to the
+    /// next vector if the current vector becomes full. Unlike [`advance_current_vector`], this
+    /// also correctly skips vectors of length zero.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `count` items after the filled part of the current vector are
+    /// initialized, and that `count` is within the bounds of the current vector.
+    ///
+    /// [`advance_current_vector`]: Self::advance_current_vector
+    unsafe fn assume_init_current_vector(&mut self, count: usize) {
+        if self.vectors_filled() == self.initializer().vectors_initialized() {
+            let end = self.items_filled_for_vector + count;
+            let items_initialized = self.initializer().items_initialized_for_current_vector();
+
+            if end >= items_initialized {
+                self.initializer
+                    .advance_current_vector(end - items_initialized);
+            }
+        }
+        self.advance_current_vector(count);
+    }
+    /// Advance the filledness cursor by `count` items, possibly spanning multiple vectors.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the filledness cursor would go beyond the initialized part, or beyond
+    /// the end of the last vector.
+    pub fn advance(&mut self, mut count: usize) {
+        while count > 0 {
+            let remaining_for_current_vector = match self.current_vector_all() {
+                Some(current) => current.len() - self.items_filled_for_vector,
+                None => panic!(
+                    "cannot advance by another {} items, since no vectors were left",
+                    count
+                ),
+            };
+
+            if remaining_for_current_vector == 0 {
+                // SAFETY: The current vector is empty, so there is nothing that needs to be
+                // initialized.
+                unsafe { self.assume_init_current_vector(0) };
+                continue;
+            }
+
+            let to_advance = core::cmp::min(count, remaining_for_current_vector);
+            self.advance_current_vector(to_advance);
+            count -= to_advance;
+        }
+    }
+    /// Advance both the filledness cursor and the initialization cursor by `count` items,
+    /// possibly spanning multiple vectors.
+    ///
+    /// # Safety
+    ///
+    /// This does not initialize nor fill anything, and it is hence up to the caller to ensure
+    /// that no uninitialized items are marked initialized.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `count` is larger than the number of items remaining.
+    pub unsafe fn assume_init(&mut self, mut count: usize) {
+        while count > 0 {
+            let remaining_for_current_vector = match self.current_vector_all() {
+                Some(current) => current.len() - self.items_filled_for_vector,
+                None => panic!(
+                    "cannot advance by another {} items, since no vectors were left",
+                    count
+                ),
+            };
+            let to_advance = core::cmp::min(count, remaining_for_current_vector);
+
+            self.assume_init_current_vector(to_advance);
+            count -= to_advance;
+        }
+    }
+    /// Append `slice` to the buffers, copying it into the unfilled parts of as many vectors as
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `slice` does not fit in the remaining vectors.
+    pub fn append(&mut self, mut slice: &[Item])
+    where
+        Item: Copy,
+    {
+        assert!(
+            slice.len() <= self.remaining(),
+            "cannot append {} items to buffers with only {} items remaining",
+            slice.len(),
+            self.remaining()
+        );
+
+        while !slice.is_empty() {
+            unsafe {
+                let unfilled = self
+                    .current_vector_unfilled_all_part_mut()
+                    .expect("expected a current vector, since the slice was checked to fit");
+                let to_copy = core::cmp::min(unfilled.len(), slice.len());
+
+                unfilled[..to_copy]
+                    .copy_from_slice(crate::cast_init_to_uninit_slice(&slice[..to_copy]));
+                self.assume_init_current_vector(to_copy);
+
+                slice = &slice[to_copy..];
+            }
+        }
+    }
 }
 impl<T> Buffers<T>
 where
@@ -528,6 +646,7 @@ where
         let Buffer {
             initializer,
             items_filled,
+            ..
         } = buffer;
 
         Self {
@@ -582,6 +701,35 @@ where
         self.inner.advance_to_current_vector_end()
     }
 }
+impl<'buffers, T, Item> BuffersRef<'buffers, T>
+where
+    T: InitializeVectored,
+    T::UninitVector: Initialize<Item = Item>,
+{
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+    /// Advance the counter of the number of items filled, possibly spanning multiple vectors.
+    ///
+    /// The number of items that are initialized is also updated accordingly, so that the number of
+    /// items initialized is always greater than or equal to the number of items filled.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the initialization invariant.
+    #[inline]
+    pub unsafe fn advance(&mut self, count: usize) {
+        self.inner.assume_init(count)
+    }
+    #[inline]
+    pub fn append(&mut self, slice: &[Item])
+    where
+        Item: Copy,
+    {
+        self.inner.append(slice)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -618,4 +766,206 @@ mod tests {
         }
         // TODO: Check that the vectors have the correct values.
     }
+    #[test]
+    fn append_and_advance() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b: [MaybeUninit<u8>; 0] = [];
+        let mut c = [MaybeUninit::uninit(); 8];
+        let mut d = [MaybeUninit::uninit(); 4];
+
+        let mut vectors = [&mut a[..], &mut b[..], &mut c[..], &mut d[..]];
+        let mut buffers = Buffers::new(&mut vectors[..]);
+        assert_eq!(buffers.remaining(), 16);
+
+        buffers.by_ref().append(b"Hello, world");
+        assert_eq!(buffers.remaining(), 4);
+        assert_eq!(buffers.vectors_filled(), 3);
+
+        {
+            let (previous, current) = buffers.all_filled_vectors();
+            assert_eq!(previous.len(), 3);
+            assert_eq!(&*previous[0], b"Hell");
+            assert_eq!(&*previous[1], b"");
+            assert_eq!(&*previous[2], b"o, world");
+            assert_eq!(current, b"");
+        }
+
+        unsafe {
+            let unfilled = buffers.current_vector_unfilled_all_part_mut().unwrap();
+            crate::fill_uninit_slice(unfilled, b'!');
+            buffers.by_ref().advance(2);
+        }
+        assert_eq!(buffers.current_vector_filled_part(), Some(&b"!!"[..]));
+        assert_eq!(buffers.remaining(), 2);
+
+        buffers.append(b"?!");
+        assert_eq!(buffers.remaining(), 0);
+        assert_eq!(buffers.vectors_filled(), 4);
+        assert_eq!(buffers.initializer().vectors_remaining(), 0);
+    }
+    #[test]
+    fn advance_over_initialized() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b = [MaybeUninit::uninit(); 4];
+
+        let mut vectors = [&mut a[..], &mut b[..]];
+        let mut buffers = Buffers::new(&mut vectors[..]);
+
+        buffers.initializer_mut().zero_current_vector_uninit_part();
+        buffers
+            .initializer_mut()
+            .partially_zero_current_vector_uninit_part(2);
+
+        buffers.advance(6);
+        assert_eq!(buffers.vectors_filled(), 1);
+        assert_eq!(buffers.remaining(), 2);
+    }
+    #[test]
+    #[should_panic]
+    fn advance_beyond_initialized() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b = [MaybeUninit::uninit(); 4];
+
+        let mut vectors = [&mut a[..], &mut b[..]];
+        let mut buffers = Buffers::new(&mut vectors[..]);
+
+        buffers.initializer_mut().zero_current_vector_uninit_part();
+        buffers.advance(5);
+    }
+    // Scatter `src` into the unfilled vectors, like `readv` would, returning the total count.
+    fn scatter(buffers: &mut Buffers<&mut [&mut [MaybeUninit<u8>]]>, mut src: &[u8]) -> usize {
+        let mut total = 0;
+
+        for vector in unsafe { buffers.initializer_mut().all_uninit_vectors_mut() } {
+            let count = core::cmp::min(vector.len(), src.len());
+            vector[..count].copy_from_slice(crate::cast_init_to_uninit_slice(&src[..count]));
+            src = &src[count..];
+            total += count;
+        }
+        total
+    }
+    #[test]
+    fn readv_two_vectors() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b = [MaybeUninit::uninit(); 4];
+
+        let mut vectors = [&mut a[..], &mut b[..]];
+        let mut buffers = Buffers::new(&mut vectors[..]);
+
+        let count = scatter(&mut buffers, b"readv!");
+        assert_eq!(count, 6);
+        unsafe { buffers.by_ref().advance(count) };
+
+        assert_eq!(buffers.vectors_filled(), 1);
+        assert_eq!(buffers.remaining(), 2);
+        let (previous, current) = buffers.all_filled_vectors();
+        assert_eq!(&*previous[0], b"read");
+        assert_eq!(current, b"v!");
+    }
+    #[test]
+    fn readv_three_vectors() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 2];
+        let mut b = [MaybeUninit::uninit(); 3];
+        let mut c = [MaybeUninit::uninit(); 4];
+
+        let mut vectors = [&mut a[..], &mut b[..], &mut c[..]];
+        let mut buffers = Buffers::new(&mut vectors[..]);
+        assert_eq!(buffers.remaining(), 9);
+
+        let count = scatter(&mut buffers, b"abcdefg");
+        assert_eq!(count, 7);
+        unsafe { buffers.by_ref().advance(count) };
+
+        assert_eq!(buffers.vectors_filled(), 2);
+        assert_eq!(buffers.remaining(), 2);
+        let (previous, current) = buffers.all_filled_vectors();
+        assert_eq!(&*previous[0], b"ab");
+        assert_eq!(&*previous[1], b"cde");
+        assert_eq!(current, b"fg");
+
+        buffers.append(b"hi");
+        assert_eq!(buffers.remaining(), 0);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn init_slice_vectors_readv() {
+        // Scatter `src` into the vectors like `readv` would, returning the total count.
+        fn fake_readv(
+            vectors: &mut [crate::wrappers::AsUninit<&mut [u8]>],
+            mut src: &[u8],
+        ) -> usize {
+            let mut total = 0;
+
+            for vector in vectors {
+                let count = core::cmp::min(vector.len(), src.len());
+                vector[..count].copy_from_slice(&src[..count]);
+                src = &src[count..];
+                total += count;
+            }
+            total
+        }
+
+        let mut storage = [0_u8; 10];
+        for vector_len in 1..=4 {
+            let mut vectors = storage.chunks_mut(vector_len).collect::<Vec<_>>();
+            let vector_count = vectors.len();
+            let mut buffers = Buffers::new(&mut vectors[..]);
+
+            let count = unsafe {
+                fake_readv(
+                    buffers.initializer_mut().all_uninit_vectors_mut(),
+                    b"dynamic!",
+                )
+            };
+            assert_eq!(count, 8);
+            unsafe { buffers.by_ref().advance(count) };
+
+            assert_eq!(buffers.total_vector_count(), vector_count);
+            assert_eq!(buffers.vectors_filled(), 8 / vector_len);
+            assert_eq!(buffers.remaining(), 2);
+        }
+        assert_eq!(&storage, b"dynamic!\0\0");
+    }
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn arrayvec_vectors() {
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b = [MaybeUninit::uninit(); 2];
+        let mut c = [MaybeUninit::uninit(); 8];
+
+        let mut vectors = arrayvec::ArrayVec::<_, 4>::new();
+        vectors.push(&mut a[..]);
+        vectors.push(&mut b[..]);
+        vectors.push(&mut c[..]);
+
+        let mut buffers = Buffers::new(vectors);
+        assert_eq!(buffers.total_vector_count(), 3);
+        assert_eq!(buffers.remaining(), 14);
+
+        buffers.append(b"scattered");
+        assert_eq!(buffers.vectors_filled(), 2);
+        assert_eq!(buffers.current_vector_filled_part(), Some(&b"red"[..]));
+
+        let (previous, _) = buffers.all_filled_vectors();
+        assert_eq!(&*previous[0], b"scat");
+        assert_eq!(&*previous[1], b"te");
+    }
+    #[test]
+    fn array_vectors() {
+        use crate::traits::InitializeVectored;
+
+        let mut a = [MaybeUninit::<u8>::uninit(); 4];
+        let mut b = [MaybeUninit::uninit(); 2];
+        let mut c = [MaybeUninit::uninit(); 8];
+
+        let vectors: [&mut [MaybeUninit<u8>]; 3] = [&mut a, &mut b, &mut c];
+        assert_eq!(vectors.as_maybe_uninit_vectors().len(), 3);
+
+        let mut buffers = Buffers::new(vectors);
+        assert_eq!(buffers.remaining(), 14);
+
+        buffers.append(b"scattered");
+        assert_eq!(buffers.vectors_filled(), 2);
+        assert_eq!(buffers.current_vector_filled_part(), Some(&b"red"[..]));
+    }
 }
@@ -148,6 +148,33 @@ where
         debug_assert!(self.capacity() >= self.items_initialized);
         self.capacity().wrapping_sub(self.items_initialized)
     }
+    /// Get the length of the initialized part, which is the same as the number of items
+    /// initialized.
+    ///
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use uninit_tools::initializer::BufferInitializer;
+    /// let mut initializer = BufferInitializer::uninit([MaybeUninit::<u8>::uninit(); 8]);
+    /// initializer.partially_zero_uninit_part(3);
+    ///
+    /// assert_eq!(initializer.init_part_len(), 3);
+    /// assert_eq!(initializer.uninit_part_len(), 5);
+    /// assert_eq!(
+    ///     initializer.init_part_len() + initializer.uninit_part_len(),
+    ///     initializer.capacity()
+    /// );
+    /// ```
+    #[inline]
+    pub fn init_part_len(&self) -> usize {
+        self.items_initialized()
+    }
+    /// Get the length of the uninitialized part, which is the same as the number of items
+    /// [`remaining`](Self::remaining). Together with [`init_part_len`](Self::init_part_len),
+    /// this always adds up to the capacity.
+    #[inline]
+    pub fn uninit_part_len(&self) -> usize {
+        self.remaining()
+    }
     /// Check whether the buffer is completely initialized. Note that this is unrelated to it being
     /// filled.
     #[inline]
@@ -378,6 +405,45 @@ where
         self.fill_uninit_part(0_u8);
         unsafe { self.advance_to_end() }
     }
+    /// Initialize the whole uninitialized part with data from `reader`, retrying on
+    /// interruptions, e.g. to prepare a scratch area. The bytes are only marked as initialized,
+    /// and not filled.
+    ///
+    /// Since [`Read`](std::io::Read) requires initialized buffers, the uninitialized part is
+    /// zeroed once before reading. If the reader reaches EOF before the uninitialized part has
+    /// been fully initialized, an error of kind
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) is returned, and only the bytes that
+    /// were read are marked as initialized.
+    #[cfg(feature = "std")]
+    pub fn init_remaining_from_reader_exact<R>(&mut self, reader: &mut R) -> std::io::Result<()>
+    where
+        R: std::io::Read + ?Sized,
+    {
+        let uninit_part = crate::fill_uninit_slice(self.uninit_part_mut(), 0_u8);
+        let mut items_read = 0;
+
+        let result = loop {
+            if items_read == uninit_part.len() {
+                break Ok(());
+            }
+            match reader.read(&mut uninit_part[items_read..]) {
+                Ok(0) => {
+                    break Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        "reader reached EOF before the buffer could be initialized",
+                    ))
+                }
+                Ok(count) => items_read += count,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => break Err(error),
+            }
+        };
+
+        // SAFETY: The bytes that were read are initialized, as they were zeroed beforehand.
+        unsafe { self.advance(items_read) }
+
+        result
+    }
 }
 
 pub struct BuffersInitializer<T> {
@@ -555,6 +621,8 @@ where
         })
     }
 
+    // NOTE: Option::is_none_or would require Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
     fn debug_assert_validity(&self) {
         debug_assert!(self
             .inner
@@ -718,6 +786,13 @@ where
         self.vectors_initialized += 1;
         self.items_initialized_for_vector = 0;
     }
+    /// Advance the initialization progress of the current vector by `count` items, continuing to
+    /// the next vector if the current vector becomes fully initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `count` additional items of the current vector, are
+    /// initialized.
     pub unsafe fn advance_current_vector(&mut self, count: usize) {
         self.debug_assert_validity();
 
@@ -824,6 +899,31 @@ mod tests {
 
             // TODO: Fill partially, and then check further.
         }
+        #[cfg(feature = "std")]
+        #[test]
+        fn init_remaining_from_reader_exact() {
+            let mut storage = [MaybeUninit::uninit(); 8];
+            let mut initializer = BufferInitializer::uninit(&mut storage[..]);
+            initializer.partially_zero_uninit_part(2);
+
+            let mut reader: &[u8] = b"scratch!";
+            initializer
+                .init_remaining_from_reader_exact(&mut reader)
+                .unwrap();
+            assert!(initializer.is_completely_init());
+            assert_eq!(initializer.init_part(), b"\0\0scratc");
+            assert_eq!(reader, b"h!");
+
+            let mut storage = [MaybeUninit::uninit(); 8];
+            let mut initializer = BufferInitializer::uninit(&mut storage[..]);
+
+            let mut reader: &[u8] = b"short";
+            let error = initializer
+                .init_remaining_from_reader_exact(&mut reader)
+                .unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+            assert_eq!(initializer.init_part(), b"short");
+        }
     }
     mod vectored {
         use super::*;
@@ -856,5 +956,55 @@ mod tests {
             assert_eq!(initializer.vectors_initialized(), 3);
             assert!(initializer.current_vector_all().is_none());
         }
+        #[cfg(all(feature = "ioslice", feature = "alloc"))]
+        #[test]
+        fn vec_of_ioslices() {
+            use ioslice::IoSliceMut;
+
+            let mut first = [MaybeUninit::uninit(); 4];
+            let mut second = [MaybeUninit::uninit(); 8];
+
+            let vectors = alloc::vec![
+                IoSliceMut::from_uninit(&mut first[..]),
+                IoSliceMut::from_uninit(&mut second[..]),
+            ];
+            let mut initializer = BuffersInitializer::uninit(vectors);
+            assert_eq!(initializer.total_vector_count(), 2);
+            assert_eq!(initializer.count_total_items_in_all_vectors(), 12);
+
+            initializer.zero_current_vector_uninit_part();
+            initializer.partially_fill_current_vector_uninit_part(5, 0x13_u8);
+            assert_eq!(initializer.vectors_initialized(), 1);
+            assert_eq!(initializer.items_initialized_for_current_vector(), 5);
+
+            initializer.fill_current_vector_uninit_part(0x37);
+            let vectors = initializer.try_into_init().ok().unwrap().into_inner();
+            assert_eq!(vectors.len(), 2);
+            drop(vectors);
+
+            let first = first.map(|item| unsafe { item.assume_init() });
+            let second = second.map(|item| unsafe { item.assume_init() });
+            assert_eq!(first, [0; 4]);
+            assert_eq!(second, [0x13, 0x13, 0x13, 0x13, 0x13, 0x37, 0x37, 0x37]);
+        }
+        #[cfg(feature = "ioslice")]
+        #[test]
+        fn array_of_ioslices() {
+            use ioslice::IoSliceMut;
+
+            let mut first = [MaybeUninit::uninit(); 2];
+            let mut second = [MaybeUninit::uninit(); 3];
+
+            let vectors = [
+                IoSliceMut::from_uninit(&mut first[..]),
+                IoSliceMut::from_uninit(&mut second[..]),
+            ];
+            let mut initializer = BuffersInitializer::uninit(vectors);
+            assert_eq!(initializer.count_total_items_in_all_vectors(), 5);
+
+            initializer.zero_current_vector_uninit_part();
+            initializer.fill_current_vector_uninit_part(0x42_u8);
+            assert!(initializer.try_into_init().is_ok());
+        }
     }
 }
@@ -92,6 +92,20 @@ unsafe impl<'a, I: InitMarker, const N: usize> InitializeVectored for [IoSliceMu
     }
 }
 
+#[cfg(feature = "alloc")]
+unsafe impl<'a, I: InitMarker> InitializeVectored for alloc::vec::Vec<IoSliceMut<'a, I>> {
+    type UninitVector = IoSliceMut<'a, Uninit>;
+
+    #[inline]
+    fn as_maybe_uninit_vectors(&self) -> &[Self::UninitVector] {
+        IoSliceMut::cast_to_uninit_slices(&self[..])
+    }
+    #[inline]
+    unsafe fn as_maybe_uninit_vectors_mut(&mut self) -> &mut [Self::UninitVector] {
+        IoSliceMut::cast_to_uninit_slices_mut(&mut self[..])
+    }
+}
+
 #[cfg(feature = "ioslice-iobox")]
 unsafe impl<I: InitMarker> Initialize for IoBox<I> {
     type Item = u8;
@@ -74,6 +74,10 @@
     feature = "nightly",
     feature(maybe_uninit_array_assume_init, new_uninit)
 )]
+#![cfg_attr(
+    all(feature = "nightly", feature = "std"),
+    feature(core_io_borrowed_buf, borrowed_buf_init, read_buf)
+)]
 use core::mem::MaybeUninit;
 
 pub mod buffer;
@@ -116,6 +120,10 @@ pub fn cast_init_to_uninit_slice<U>(init: &[U]) -> &[MaybeUninit<U>] {
 ///
 /// The initialization variant must be upheld; that is, the caller must ensure that the buffer
 /// cannot contain any uninitialized data.
+///
+/// Note that this applies even if every bit pattern is valid for `U` (e.g. for integers, or types
+/// implementing `bytemuck::Pod`). Uninitialized memory is not merely an arbitrary bit pattern, and
+/// reading it as `U` is undefined behavior regardless of the type.
 #[inline]
 pub unsafe fn cast_uninit_to_init_slice<U>(uninit: &[MaybeUninit<U>]) -> &[U] {
     cast_slice_same_layout(uninit)
@@ -1,6 +1,6 @@
 use core::mem::MaybeUninit;
 
-use crate::wrappers::AssertInit;
+use crate::wrappers::{AsUninit, AssertInit};
 
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, rc::Rc, string::String, sync::Arc, vec::Vec};
@@ -85,20 +85,11 @@ pub trait InitializeExt: private2::Sealed + Initialize + Sized {
 mod private2 {
     pub trait Sealed {}
 }
-mod private3 {
-    pub trait Sealed {}
-}
-mod private4 {
-    pub trait Sealed {}
-}
-mod private5 {
-    pub trait Sealed {}
-}
 
 impl<T> private2::Sealed for T where T: Initialize {}
 impl<T> InitializeExt for T where T: Initialize {}
 
-unsafe impl<'a, T> Initialize for &'a mut [MaybeUninit<T>] {
+unsafe impl<T> Initialize for &mut [MaybeUninit<T>] {
     type Item = T;
 
     #[inline]
@@ -116,6 +107,37 @@ impl<'a, T> From<AssertInit<&'a mut [MaybeUninit<T>]>> for &'a mut [T] {
         unsafe { crate::cast_uninit_to_init_slice_mut(init_slice.into_inner()) }
     }
 }
+/// Borrowed arrays can be used directly, without having to reslice them first:
+///
+/// ```
+/// # use core::mem::MaybeUninit;
+/// # use uninit_tools::buffer::Buffer;
+/// let mut array = [MaybeUninit::<u8>::uninit(); 8];
+/// let mut buffer = Buffer::uninit(&mut array);
+///
+/// buffer.append(b"Hello");
+/// assert_eq!(buffer.filled_part(), b"Hello");
+/// assert_eq!(buffer.remaining(), 3);
+/// ```
+unsafe impl<T, const N: usize> Initialize for &mut [MaybeUninit<T>; N] {
+    type Item = T;
+
+    #[inline]
+    fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        *self
+    }
+    #[inline]
+    unsafe fn as_maybe_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        *self
+    }
+}
+impl<'a, T, const N: usize> From<AssertInit<&'a mut [MaybeUninit<T>; N]>> for &'a mut [T; N] {
+    #[inline]
+    fn from(init_array: AssertInit<&'a mut [MaybeUninit<T>; N]>) -> &'a mut [T; N] {
+        // SAFETY: MaybeUninit<T> has the same layout as T, and hence so do arrays of them.
+        unsafe { &mut *(init_array.into_inner() as *mut [MaybeUninit<T>; N] as *mut [T; N]) }
+    }
+}
 /*unsafe impl<T> InitializeVectored for T
 where
     T: Initialize,
@@ -131,12 +153,56 @@ where
         core::slice::from_mut(self)
     }
 }*/
-unsafe impl<'a, 'b, T> InitializeVectored for &'a mut [&'b mut [MaybeUninit<T>]] {
+unsafe impl<'b, T> InitializeVectored for &mut [&'b mut [MaybeUninit<T>]] {
+    type UninitVector = &'b mut [MaybeUninit<T>];
+
+    fn as_maybe_uninit_vectors(&self) -> &[Self::UninitVector] {
+        self
+    }
+    unsafe fn as_maybe_uninit_vectors_mut(&mut self) -> &mut [Self::UninitVector] {
+        self
+    }
+}
+// NOTE: This is not a blanket impl over arrays of any `Initialize` type, since that would overlap
+// with the impl for arrays of I/O slices.
+unsafe impl<'b, T, const N: usize> InitializeVectored for [&'b mut [MaybeUninit<T>]; N] {
+    type UninitVector = &'b mut [MaybeUninit<T>];
+
+    #[inline]
+    fn as_maybe_uninit_vectors(&self) -> &[Self::UninitVector] {
+        self
+    }
+    #[inline]
+    unsafe fn as_maybe_uninit_vectors_mut(&mut self) -> &mut [Self::UninitVector] {
+        self
+    }
+}
+// SAFETY: AsUninit is #[repr(transparent)], and thus has the same layout as the initialized
+// slices it wraps. Since AsUninit only presents them as uninitialized, they can never be
+// de-initialized.
+unsafe impl<'b> InitializeVectored for &mut [&'b mut [u8]] {
+    type UninitVector = AsUninit<&'b mut [u8]>;
+
+    fn as_maybe_uninit_vectors(&self) -> &[Self::UninitVector] {
+        unsafe { crate::cast_slice_same_layout(self) }
+    }
+    unsafe fn as_maybe_uninit_vectors_mut(&mut self) -> &mut [Self::UninitVector] {
+        crate::cast_slice_same_layout_mut(self)
+    }
+}
+// NOTE: Only the vectors within the length of the ArrayVec are used, and its length cannot change
+// while wrapped.
+#[cfg(feature = "arrayvec")]
+unsafe impl<'b, T, const N: usize> InitializeVectored
+    for arrayvec::ArrayVec<&'b mut [MaybeUninit<T>], N>
+{
     type UninitVector = &'b mut [MaybeUninit<T>];
 
+    #[inline]
     fn as_maybe_uninit_vectors(&self) -> &[Self::UninitVector] {
         self
     }
+    #[inline]
     unsafe fn as_maybe_uninit_vectors_mut(&mut self) -> &mut [Self::UninitVector] {
         self
     }
@@ -183,18 +249,60 @@ unsafe impl Initialize for Vec<Item> {
         // set_len is safe.
         crate::cast_init_to_uninit_slice_mut(&mut *self)
     }
-}
+}*/
+// NOTE: The slice only covers the length of the vector, and not its full capacity. Since the
+// vector is owned by the initializer, its length cannot change while wrapped.
 #[cfg(feature = "alloc")]
-unsafe impl Initialize for Vec<MaybeUninit<u8>> {
+unsafe impl<T> Initialize for Vec<MaybeUninit<T>> {
+    type Item = T;
+
     #[inline]
-    fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<u8>] {
-        &*self
+    fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        self
     }
     #[inline]
-    unsafe fn as_maybe_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<u8>] {
-        &mut *self
+    unsafe fn as_maybe_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
     }
-}*/
+}
+// NOTE: Like for Vec, the slice only covers the length of the SmallVec, regardless of whether
+// it is stored inline or on the heap.
+#[cfg(feature = "smallvec")]
+unsafe impl<T, const N: usize> Initialize for smallvec::SmallVec<[MaybeUninit<T>; N]> {
+    type Item = T;
+
+    #[inline]
+    fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+    #[inline]
+    unsafe fn as_maybe_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+}
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> From<AssertInit<smallvec::SmallVec<[MaybeUninit<T>; N]>>>
+    for smallvec::SmallVec<[T; N]>
+{
+    fn from(init_vec: AssertInit<smallvec::SmallVec<[MaybeUninit<T>; N]>>) -> Self {
+        let vec = init_vec.into_inner();
+
+        if vec.spilled() {
+            let mut vec = core::mem::ManuallyDrop::new(vec);
+            let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+
+            // SAFETY: The heap allocation is reused as is, since MaybeUninit<T> has the same
+            // layout as T, and every item within the length is initialized.
+            unsafe { smallvec::SmallVec::from_raw_parts(ptr as *mut T, len, cap) }
+        } else {
+            // NOTE: Inline storage cannot be reinterpreted in place, but moving at most N items
+            // is cheap.
+            vec.into_iter()
+                .map(|item| unsafe { item.assume_init() })
+                .collect()
+        }
+    }
+}
 #[cfg(feature = "alloc")]
 impl<T> From<AssertInit<Vec<MaybeUninit<T>>>> for Vec<T> {
     #[inline]
@@ -213,7 +321,7 @@ impl<T> From<AssertInit<Vec<MaybeUninit<T>>>> for Vec<T> {
                 (ptr, cap, len)
             };
 
-            Vec::from_raw_parts(ptr as *mut T, cap, len)
+            Vec::from_raw_parts(ptr as *mut T, len, cap)
         }
     }
 }
@@ -245,6 +353,26 @@ impl<T, const N: usize> From<AssertInit<[MaybeUninit<T>; N]>> for [T; N] {
     }
 }
 
+/// A trait for [`Initialize`] types that can be grown, appending additional possibly-uninitialized
+/// items to the end of their slices.
+///
+/// # Safety
+///
+/// Growing must keep the existing items intact, both in value and in initializedness, so that
+/// the only change to the slices returned by [`Initialize`], is that `additional` items have been
+/// appended at the end.
+pub unsafe trait GrowableBacking: Initialize {
+    /// Extend the slice by `additional` possibly-uninitialized items.
+    fn grow(&mut self, additional: usize);
+}
+#[cfg(feature = "alloc")]
+unsafe impl<T> GrowableBacking for Vec<MaybeUninit<T>> {
+    #[inline]
+    fn grow(&mut self, additional: usize) {
+        self.resize_with(self.len() + additional, MaybeUninit::uninit);
+    }
+}
+
 /// A marker trait for implementations of [`Deref`](core::ops::Deref) that come with the additional
 /// guarantee that:
 ///
@@ -275,6 +403,10 @@ impl<T, const N: usize> From<AssertInit<[MaybeUninit<T>; N]>> for [T; N] {
 /// make assumptions about the length always being correct, the actual address of the memory is of
 /// no importance. However, implementing `StableDeref` means that invariant 3 is always upheld, but
 /// it is not clear at the moment whether that also applies to invariant 1 and 2.
+///
+/// # Safety
+///
+/// Implementors must uphold all three invariants listed above.
 pub unsafe trait TrustedDeref: core::ops::Deref {}
 
 // TODO: Respect the allocator type of must liballoc collections, at least under #[cfg(feature =
@@ -282,10 +414,10 @@ pub unsafe trait TrustedDeref: core::ops::Deref {}
 
 // SAFETY: Deref for references is always a no-op, and always returns `self`. Unless the caller
 // changes the length of it beforehand, nothing bad will happen.
-unsafe impl<'a, T: ?Sized> TrustedDeref for &'a T {}
+unsafe impl<T: ?Sized> TrustedDeref for &T {}
 
 // SAFETY: DerefMut for references is always a no-op.
-unsafe impl<'a, T: ?Sized> TrustedDeref for &'a mut T {}
+unsafe impl<T: ?Sized> TrustedDeref for &mut T {}
 
 // SAFETY: The resulting slice is determined by the internal `len` field of the vector. The
 // dereference impls will not change this.
@@ -307,10 +439,10 @@ unsafe impl<T: ?Sized> TrustedDeref for Rc<T> {}
 
 // SAFETY: While RefCell allows inner types to utilize interior mutability, the actual RAII guard
 // will not do anything wrong.
-unsafe impl<'a, T: ?Sized> TrustedDeref for core::cell::Ref<'a, T> {}
+unsafe impl<T: ?Sized> TrustedDeref for core::cell::Ref<'_, T> {}
 
 // SAFETY: Same goes for RefMut.
-unsafe impl<'a, T: ?Sized> TrustedDeref for core::cell::RefMut<'a, T> {}
+unsafe impl<T: ?Sized> TrustedDeref for core::cell::RefMut<'_, T> {}
 
 // SAFETY: Same goes for all lock guards.
 #[cfg(feature = "std")]
@@ -355,9 +487,25 @@ unsafe impl<'a, T: alloc::borrow::ToOwned> TrustedDeref for alloc::borrow::Cow<'
 /// properly. For single-buffer I/O, this allows converting between any [`Equivalent`] types, where
 /// for vectored I/O, it allows a more restricted type of conversion, where they also need to be
 /// slice-castable.
+///
+/// # Safety
+///
+/// Both types must have the exact same layout, and their [`Initialize`] implementations must
+/// return the same slices.
 pub unsafe trait Equivalent<T>
 where
     T: Initialize,
     Self: Initialize<Item = <T as Initialize>::Item>,
 {
 }
+
+/// A trait for types that can provide a cheap sanity check of whether they appear to be
+/// initialized, e.g. by looking for a sentinel pattern that uninitialized memory is filled with.
+///
+/// Since initializedness cannot be verified in general, this is only a heuristic, used by
+/// [`AssertInit::new_debug_checked`] to catch obvious mistakes in debug builds. It must therefore
+/// never be relied upon for soundness.
+pub trait DebugVerifiableInit {
+    /// Check whether the value looks initialized. Returning `true` does not guarantee that it is.
+    fn looks_initialized(&self) -> bool;
+}
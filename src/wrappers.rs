@@ -2,7 +2,7 @@ use core::borrow::{Borrow, BorrowMut};
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
 
-use crate::traits::{Initialize, InitializeVectored, TrustedDeref};
+use crate::traits::{DebugVerifiableInit, Initialize, InitializeVectored, TrustedDeref};
 
 /// A wrapper over `T` that assumes all of `T` to be initialized.
 #[repr(transparent)]
@@ -22,6 +22,28 @@ impl<T> AssertInit<T> {
     pub const unsafe fn new_unchecked(inner: T) -> Self {
         Self { inner }
     }
+    /// Wrap `inner`, assuming that it is fully initialized, but with a sanity check via
+    /// [`DebugVerifiableInit`] in debug builds. In release builds, this is identical to
+    /// [`new_unchecked`](Self::new_unchecked).
+    ///
+    /// # Safety
+    ///
+    /// The same as for [`new_unchecked`](Self::new_unchecked); the check is only a heuristic.
+    ///
+    /// # Panics
+    ///
+    /// This will panic in debug builds, if `inner` does not look initialized.
+    #[inline]
+    pub unsafe fn new_debug_checked(inner: T) -> Self
+    where
+        T: DebugVerifiableInit,
+    {
+        debug_assert!(
+            inner.looks_initialized(),
+            "value asserted to be initialized failed its sanity check"
+        );
+        Self::new_unchecked(inner)
+    }
     /// Cast `&[T]` to `&[AssertInit<T>]`.
     ///
     /// # Safety
@@ -135,6 +157,23 @@ where
         self.inner_mut().as_maybe_uninit_slice_mut()
     }
 }
+#[cfg(feature = "alloc")]
+impl<T> AssertInit<alloc::boxed::Box<[MaybeUninit<T>]>> {
+    /// Turn the initialized boxed slice into a boxed slice of initialized items, without copying.
+    #[inline]
+    pub fn into_init_box(self) -> alloc::boxed::Box<[T]> {
+        self.into()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> AssertInit<alloc::vec::Vec<MaybeUninit<T>>> {
+    /// Turn the initialized vector into a vector of initialized items, without copying. The
+    /// capacity of the vector is kept.
+    #[inline]
+    pub fn into_init_vec(self) -> alloc::vec::Vec<T> {
+        self.into()
+    }
+}
 impl<T> AsRef<[T::Item]> for AssertInit<T>
 where
     T: Initialize,
@@ -239,6 +278,11 @@ pub struct AssertInitVectors<I> {
     inner: I,
 }
 impl<I> AssertInitVectors<I> {
+    /// Wrap the vectors `inner`, assuming that all of them are fully initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every vector in `inner` is fully initialized.
     pub const unsafe fn new_unchecked(inner: I) -> Self {
         Self { inner }
     }
@@ -358,7 +402,7 @@ where
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &*self.0
+        &self.0
     }
 }
 impl<T> DerefMut for AsUninit<T>
@@ -367,7 +411,7 @@ where
 {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut *self.0
+        &mut self.0
     }
 }
 
@@ -379,7 +423,7 @@ where
 
     #[inline]
     fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<Item>] {
-        let slice: &[Item] = &*self;
+        let slice: &[Item] = self;
         crate::cast_init_to_uninit_slice(slice)
     }
     #[inline]
@@ -388,3 +432,131 @@ where
         crate::cast_init_to_uninit_slice_mut(slice_mut)
     }
 }
+
+/// A wrapper over the spare capacity of a [`heapless::Vec`], i.e. the items past its length,
+/// which can be filled via a [`Buffer`](crate::buffer::Buffer) without any heap allocation.
+///
+/// Once filled, the filled items can be appended to the vector using
+/// [`Buffer::commit_to_vec`](crate::buffer::Buffer::commit_to_vec).
+#[cfg(feature = "heapless")]
+#[derive(Debug)]
+pub struct HeaplessSpareCapacity<'a, T, const N: usize> {
+    pub(crate) vec: &'a mut heapless::Vec<T, N>,
+}
+#[cfg(feature = "heapless")]
+impl<'a, T, const N: usize> HeaplessSpareCapacity<'a, T, N> {
+    #[inline]
+    pub fn new(vec: &'a mut heapless::Vec<T, N>) -> Self {
+        Self { vec }
+    }
+}
+// SAFETY: The vector is borrowed mutably for as long as it is wrapped, so its length cannot change
+// in the meantime. The items past the length of a heapless vector are never read by it.
+#[cfg(feature = "heapless")]
+unsafe impl<T, const N: usize> Initialize for HeaplessSpareCapacity<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn as_maybe_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        let len = self.vec.len();
+
+        unsafe {
+            core::slice::from_raw_parts(
+                self.vec.as_ptr().add(len) as *const MaybeUninit<T>,
+                N - len,
+            )
+        }
+    }
+    #[inline]
+    unsafe fn as_maybe_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len = self.vec.len();
+
+        core::slice::from_raw_parts_mut(
+            self.vec.as_mut_ptr().add(len) as *mut MaybeUninit<T>,
+            N - len,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENTINEL: u8 = 0xAA;
+
+    struct Sentineled([MaybeUninit<u8>; 4]);
+
+    impl DebugVerifiableInit for Sentineled {
+        fn looks_initialized(&self) -> bool {
+            self.0
+                .iter()
+                .all(|byte| unsafe { byte.assume_init() } != SENTINEL)
+        }
+    }
+
+    #[test]
+    fn new_debug_checked() {
+        let inner = Sentineled([MaybeUninit::new(1); 4]);
+        let init = unsafe { AssertInit::new_debug_checked(inner) };
+        assert_eq!(unsafe { init.into_inner().0[3].assume_init() }, 1);
+    }
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn new_debug_checked_sentinel() {
+        let mut inner = Sentineled([MaybeUninit::new(SENTINEL); 4]);
+        inner.0[0] = MaybeUninit::new(0);
+        let _ = unsafe { AssertInit::new_debug_checked(inner) };
+    }
+    #[test]
+    fn into_init_slice() {
+        let mut array = [MaybeUninit::new(1_u8); 4];
+        let init = unsafe { AssertInit::new_unchecked(&mut array[..]) };
+        let slice: &mut [u8] = init.into();
+        assert_eq!(slice, &[1; 4]);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_init_box() {
+        use alloc::boxed::Box;
+
+        let boxed: Box<[MaybeUninit<u16>]> = Box::new([MaybeUninit::new(7); 3]);
+        let init = unsafe { AssertInit::new_unchecked(boxed) };
+        assert_eq!(&*init.into_init_box(), &[7; 3]);
+
+        let boxed: Box<[MaybeUninit<u16>]> = Box::new([MaybeUninit::new(7); 3]);
+        let init = unsafe { AssertInit::new_unchecked(boxed) };
+        assert_eq!(&*Box::<[u16]>::from(init), &[7; 3]);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_init_vec() {
+        use alloc::vec::Vec;
+
+        let vec = alloc::vec![MaybeUninit::new(1_u32); 8];
+        let init = unsafe { AssertInit::new_unchecked(vec) };
+        let vec = init.into_init_vec();
+        assert_eq!(vec, [1; 8]);
+        assert_eq!(vec.capacity(), 8);
+
+        let vec = Vec::<MaybeUninit<u32>>::with_capacity(4);
+        let init = unsafe { AssertInit::new_unchecked(vec) };
+        let vec = init.into_init_vec();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), 4);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_from_assert_init_with_spare_capacity() {
+        use alloc::vec::Vec;
+
+        let mut vec = Vec::with_capacity(16);
+        vec.extend(b"init".iter().copied().map(MaybeUninit::new));
+
+        let init = unsafe { AssertInit::new_unchecked(vec) };
+        let vec = Vec::<u8>::from(init);
+        assert_eq!(vec, b"init");
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.capacity(), 16);
+    }
+}